@@ -1,3 +1,16 @@
+use std::fmt::Display;
+use utils::solution::Solution;
+
+fn process(s: &str) -> Option<usize> {
+    let l = s.chars().filter(|&c| char::is_numeric(c)).next()?;
+    let r = s.chars().rev().filter(|&c| char::is_numeric(c)).next()?;
+
+    let mut number = String::from(l);
+    number.push(r);
+
+    usize::from_str_radix(&number, 10).ok()
+}
+
 fn usize_from_token(tok: &str) -> Option<usize> {
     match tok {
         "0" | "zero" => Some(0),
@@ -30,7 +43,8 @@ fn find_first(s: &str, tokens: &Vec<String>) -> Option<String> {
         .map(|(tok, _n)| tok.clone())
 }
 
-fn process(s: &str) -> Option<usize> {
+/// like `process` but digits may also be spelled out with letters
+fn process_with_words(s: &str) -> Option<usize> {
     let tokens = vec![
         "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "zero", "one", "two", "three", "four",
         "five", "six", "seven", "eight", "nine",
@@ -55,24 +69,30 @@ fn process(s: &str) -> Option<usize> {
     }
 }
 
-use std::fs;
-use std::io::Read;
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<String>;
 
-fn main() {
-    let mut file = fs::File::open("input").unwrap();
-    let mut s = String::new();
-    file.read_to_string(&mut s).unwrap();
+    fn parse(input: &str) -> Self::Input {
+        input.split('\n').map(String::from).collect()
+    }
 
-    let lines: Vec<&str> = s.split('\n').collect();
+    fn part1(input: &Self::Input) -> impl Display {
+        input.iter().filter_map(|line| process(line)).sum::<usize>()
+    }
 
-    let mut sum = 0;
-    for line in lines {
-        let rv = process(line);
-        println!("{} => {:?}", line, rv);
-        if let Some(rv) = rv {
-            sum += rv;
-        }
+    fn part2(input: &Self::Input) -> impl Display {
+        input
+            .iter()
+            .filter_map(|line| process_with_words(line))
+            .sum::<usize>()
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
 
-    println!("sum = {sum}");
+    utils::example_tests!(Puzzle; example1, example2);
 }
@@ -1,5 +1,8 @@
+use std::fmt::Display;
+use utils::solution::Solution;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-struct XY {
+pub struct XY {
     pub x: isize,
     pub y: isize,
 }
@@ -33,7 +36,8 @@ impl XY {
     const LEFT: XY = XY { x: -1, y: 0 };
     const RIGHT: XY = XY { x: 1, y: 0 };
 }
-struct Field {
+#[derive(Clone)]
+pub struct Field {
     width: usize,
     height: usize,
     orig: Vec<char>,
@@ -50,6 +54,7 @@ impl Field {
         }
     }
 
+    #[allow(unused)]
     pub fn print(&self) {
         let tiles = self.tiles.chunks(self.width);
         for (line, oline) in tiles.zip(self.orig.chunks(self.width)) {
@@ -57,7 +62,7 @@ impl Field {
             line.iter().for_each(|&c| s.push(c));
             s.push(' ');
             oline.iter().for_each(|&c| s.push(c));
-            println!("{s}");
+            utils::debug!("{s}");
         }
     }
 
@@ -224,7 +229,8 @@ impl Field {
         area
     }
 
-    pub fn solve(&mut self) {
+    /// mark loop tiles, return number of steps to farthest loop position
+    pub fn solve(&mut self) -> usize {
         let start = self.find_start();
 
         // find starting position neighboor pipes
@@ -263,65 +269,41 @@ impl Field {
         self.set_tile(a, 'M');
         self.set_tile(b, 'M');
 
-        println!("{a:?} {b:?} {steps}");
+        steps
     }
 }
 
-fn main() {
-    /*
-        let data = ".....
-        .S-7.
-        .|.|.
-        .L-J.
-        .....";
-        let data = "..F7.
-    .FJ|.
-    SJ.L7
-    |F--J
-    LJ...";
-    */
-    /*
-        let data = "...........
-    .S-------7.
-    .|F-----7|.
-    .||.....||.
-    .||.....||.
-    .|L-7.F-J|.
-    .|..|.|..|.
-    .L--J.L--J.
-    ...........";
-    */
-
-    /*
-        let data = ".F----7F7F7F7F-7....
-    .|F--7||||||||FJ....
-    .||.FJ||||||||L7....
-    FJL7L7LJLJ||LJ.L-7..
-    L--J.L7...LJS7F-7L7.
-    ....F-J..F7FJ|L7L7L7
-    ....L7.F7||L7|.L7L7|
-    .....|FJLJ|FJ|F7|.LJ
-    ....FJL-7.||.||||...
-    ....L---J.LJ.LJLJ...";
-    */
-    let mut data = String::new();
-    let mut file = std::fs::File::open("input").unwrap();
-    use std::io::Read;
-    file.read_to_string(&mut data).unwrap();
-
-    let mut f = Field::new();
-    for line in data.split('\n') {
-        let line = line.trim();
-        if line.len() == 0 {
-            continue;
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Field;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut f = Field::new();
+        for line in input.split('\n') {
+            let line = line.trim();
+            if line.len() == 0 {
+                continue;
+            }
+            f.push_line(line);
         }
-        f.push_line(line);
+        f
     }
 
-    f.solve();
+    fn part1(input: &Self::Input) -> impl Display {
+        input.clone().solve()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let mut f = input.clone();
+        f.solve();
+        f.measure_marked_area()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
 
-    let area = f.measure_marked_area();
-    f.print();
-    println!("area = {area}");
-    // use first line to guess
+    utils::example_tests!(Puzzle; example1, example2, example3, example4);
 }
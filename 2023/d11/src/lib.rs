@@ -1,3 +1,6 @@
+use std::fmt::Display;
+use utils::solution::Solution;
+
 struct CombinePairs {
     i: usize,
     j: usize,
@@ -29,7 +32,7 @@ impl Iterator for CombinePairs {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-struct XY {
+pub struct XY {
     pub x: isize,
     pub y: isize,
 }
@@ -90,7 +93,8 @@ pub fn grid_distance(a: XY, b: XY) -> isize {
     delta.x.abs() + delta.y.abs()
 }
 
-struct Universe {
+#[derive(Clone)]
+pub struct Universe {
     width: usize,
     height: usize,
     tiles: Vec<char>,
@@ -113,12 +117,13 @@ impl Universe {
         }
     }
 
+    #[allow(unused)]
     pub fn print(&self) {
         let tiles = self.tiles.chunks(self.width);
         for line in tiles {
             let mut s = String::new();
             line.iter().for_each(|&c| s.push(c));
-            println!("{s}");
+            utils::debug!("{s}");
         }
     }
 
@@ -185,13 +190,15 @@ impl Universe {
                 let here = XY::new(x as isize, y as isize);
 
                 if self.get_tile(here) == '#' {
+                    // number the first galaxies, to ease debugging on examples
                     let i = self.galaxies.len();
-                    self.set_tile(here, char::from_digit(i as u32, 10).unwrap());
+                    if let Some(c) = char::from_digit(i as u32, 10) {
+                        self.set_tile(here, c);
+                    }
                     self.galaxies.push(here);
                 }
             }
         }
-        println!("{:?}", self.galaxies);
     }
 
     pub fn distance_between(&self, a: usize, b: usize) -> usize {
@@ -216,7 +223,7 @@ impl Universe {
             .iter()
             .filter(|&&x| {
                 let x = x as isize;
-                ga.x < x && x < gb.x
+                ga.x.min(gb.x) < x && x < ga.x.max(gb.x)
             })
             .count();
         // find empty spaces inside ga and gb along y
@@ -225,7 +232,7 @@ impl Universe {
             .iter()
             .filter(|&&y| {
                 let y = y as isize;
-                ga.y < y && y < gb.y
+                ga.y.min(gb.y) < y && y < ga.y.max(gb.y)
             })
             .count();
 
@@ -239,25 +246,55 @@ impl Universe {
         let mut sum = 0;
         let n = self.galaxies.len();
         // for each pair of galaxies
-        let mut i = 0;
         for (a, b) in CombinePairs::new(n) {
-            let distance = self.distance_between(a, b);
-            println!("{a} {b} {distance}");
-            sum += distance;
-            i += 1;
+            sum += self.distance_between(a, b);
         }
-        println!("i={i}");
         sum
     }
 }
 
-fn main() {
-    assert_eq!(grid_distance(XY::new(0, 0), XY::new(1, 0)), 1);
-    assert_eq!(grid_distance(XY::new(0, 0), XY::new(10, 0)), 10);
-    assert_eq!(grid_distance(XY::new(0, 0), XY::new(0, 10)), 10);
-    assert_eq!(grid_distance(XY::new(10, 0), XY::new(0, 0)), 10);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Universe;
+
+    const SOLVED: [bool; 2] = [true, false];
 
-    let data = "...#......
+    fn parse(input: &str) -> Self::Input {
+        let mut f = Universe::new();
+        for line in input.split('\n') {
+            let line = line.trim();
+            if line.len() == 0 {
+                continue;
+            }
+            f.push_line(line);
+        }
+        f
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        let mut f = input.clone();
+        f.expand(2);
+        f.count_galaxies();
+        f.answer1()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        assert_eq!(grid_distance(XY::new(0, 0), XY::new(1, 0)), 1);
+        assert_eq!(grid_distance(XY::new(0, 0), XY::new(10, 0)), 10);
+        assert_eq!(grid_distance(XY::new(0, 0), XY::new(0, 10)), 10);
+        assert_eq!(grid_distance(XY::new(10, 0), XY::new(0, 0)), 10);
+    }
+
+    #[test]
+    fn example() {
+        let input = "...#......
                 .......#..
                 #.........
                 ..........
@@ -268,47 +305,29 @@ fn main() {
                 .......#..
                 #...#.....";
 
-    /*
-    11 + 3
-    ....1........
-    .........2...
-    3............
-    .............
-    .............
-    ........4....
-    .5...........
-    .##.........6
-    ..##.........
-    ...##........
-    ....##...7...
-    8....9.......
-    */
-    /*
-        let mut data = String::new();
-        let mut file = std::fs::File::open("input").unwrap();
-        use std::io::Read;
-        file.read_to_string(&mut data).unwrap();
-    */
-    let mut f = Universe::new();
-    for line in data.split('\n') {
-        let line = line.trim();
-        if line.len() == 0 {
-            continue;
-        }
-        f.push_line(line);
+        /*
+        11 + 3
+        ....1........
+        .........2...
+        3............
+        .............
+        .............
+        ........4....
+        .5...........
+        .##.........6
+        ..##.........
+        ...##........
+        ....##...7...
+        8....9.......
+        */
+        let mut f = Puzzle::parse(input);
+        f.expand(2);
+        f.count_galaxies();
+
+        assert_eq!(f.distance_between(0, 1), 6);
+        assert_eq!(f.distance_between(0, 6), 15);
+        assert_eq!(f.distance_between(2, 5), 17);
+        assert_eq!(f.distance_between(7, 8), 5);
+        assert_eq!(f.answer1(), 374);
     }
-
-    f.print();
-    f.expand(2);
-    f.count_galaxies();
-    f.print();
-
-    assert_eq!(f.distance_between(0, 1), 6);
-    assert_eq!(f.distance_between(0, 6), 15);
-    assert_eq!(f.distance_between(2, 5), 17);
-    assert_eq!(f.distance_between(7, 8), 5);
-
-    let ans1 = f.answer1();
-    println!("ans1 = {ans1}");
-    // use first line to guess
 }
@@ -1,11 +1,14 @@
+use std::fmt::Display;
+use utils::solution::Solution;
+
 #[derive(Debug)]
-enum Symmetry {
+pub enum Symmetry {
     None,
     Horizontal(usize),
     Vertical(usize),
 }
 
-struct Pattern {
+pub struct Pattern {
     map: Vec<char>,
     width: usize,
     height: usize,
@@ -37,15 +40,16 @@ impl Pattern {
         self.height += 1;
     }
 
+    #[allow(unused)]
     pub fn print(&self) {
-        println!("   0123456789ABCDEF");
+        utils::debug!("   0123456789ABCDEF");
         let lines = self.map.chunks(self.width).enumerate();
         for (y, line) in lines {
             let mut s = String::new();
             for (x, &c) in line.iter().enumerate() {
                 s.push(c);
             }
-            println!("{y:2} {s}");
+            utils::debug!("{y:2} {s}");
         }
     }
 
@@ -153,66 +157,64 @@ impl Pattern {
     }
 }
 
-fn main() {
-    /*
-    let mut data = "#.##..##.
-    ..#.##.#.
-    ##......#
-    ##......#
-    ..#.##.#.
-    ..##..##.
-    #.#.##.#.
-
-    #...##..#
-    #....#..#
-    ..##..###
-    #####.##.
-    #####.##.
-    ..##..###
-    #....#..#";
-    */
-    let mut data = String::new();
-    let mut file = std::fs::File::open("input").unwrap();
-    use std::io::Read;
-    file.read_to_string(&mut data).unwrap();
-
-    let mut lines = data.split('\n');
-    let mut patterns: Vec<Pattern> = vec![];
-    let mut run = true;
-    while run {
-        let mut pattern = Pattern::new();
-        loop {
-            match lines.next() {
-                Some(line) => {
-                    let line = line.trim();
-                    if line.len() == 0 {
+/// sum up reflection lines found in each pattern
+fn summarize(patterns: &[Pattern], find: fn(&Pattern) -> Symmetry) -> usize {
+    let mut answer = 0usize;
+    for pat in patterns {
+        match find(pat) {
+            Symmetry::Horizontal(x) => answer += 100 * x,
+            Symmetry::Vertical(x) => answer += x,
+            Symmetry::None => unreachable!(),
+        }
+    }
+    answer
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Pattern>;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut lines = input.split('\n');
+        let mut patterns: Vec<Pattern> = vec![];
+        let mut run = true;
+        while run {
+            let mut pattern = Pattern::new();
+            loop {
+                match lines.next() {
+                    Some(line) => {
+                        let line = line.trim();
+                        if line.len() == 0 {
+                            break;
+                        }
+                        pattern.push(line);
+                    }
+                    None => {
+                        run = false;
                         break;
                     }
-                    pattern.push(line);
-                }
-                None => {
-                    run = false;
-                    break;
                 }
             }
+            if !pattern.empty() {
+                patterns.push(pattern);
+            }
         }
-        if !pattern.empty() {
-            patterns.push(pattern);
-        }
+        patterns
     }
 
-    let mut answer = 0usize;
-    for (n, pat) in patterns.iter().enumerate() {
-        println!("PATTERN {n}");
-        pat.print();
-        let x = pat.find_smudge();
-        println!("{x:?}");
-        match x {
-            Symmetry::Horizontal(x) => answer += 100 * x,
-            Symmetry::Vertical(x) => answer += x,
-            Symmetry::None => unreachable!(),
-        }
+    fn part1(input: &Self::Input) -> impl Display {
+        summarize(input, Pattern::find_symetry)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        summarize(input, Pattern::find_smudge)
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
 
-    println!("answer = {answer}");
+    utils::example_tests!(Puzzle; example);
 }
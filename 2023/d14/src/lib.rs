@@ -1,4 +1,6 @@
+use std::fmt::Display;
 use utils::asciimap::{AsciiMap, Rotation};
+use utils::solution::Solution;
 
 pub struct Platform {
     map: AsciiMap,
@@ -103,32 +105,35 @@ fn find_repetition(ws: &Vec<usize>) -> Option<(usize, Vec<usize>)> {
     None
 }
 
-fn main() {
-    /*
-    let data = "O....#....
-    O.OO#....#
-    .....##...
-    OO.#O....O
-    .O.....O#.
-    O.#..O.#.#
-    ..O..#O..O
-    .......O..
-    #....###..
-    #OO..#....";
-    */
-
-    let mut data = String::new();
-    let mut file = std::fs::File::open("input").unwrap();
-    use std::io::Read;
-    file.read_to_string(&mut data).unwrap();
-
-    let map = AsciiMap::from_multi_lines(data);
-    let mut platform = Platform::new(map);
-    let ws = platform.cycle(1000);
-    let (s, vs) = find_repetition(&ws).unwrap();
-    println!("{s} {vs:?}");
-    let n = 1_000_000_000;
-
-    let k = (n - s - 1) % vs.len();
-    println!("{k} {}", vs[k]);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = AsciiMap;
+
+    fn parse(input: &str) -> Self::Input {
+        AsciiMap::from_multi_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        let mut map = input.clone();
+        Platform::slide(&mut map);
+        Platform::load(&map)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let mut platform = Platform::new(input.clone());
+        let ws = platform.cycle(1000);
+        let (s, vs) = find_repetition(&ws).unwrap();
+        let n = 1_000_000_000;
+
+        let k = (n - s - 1) % vs.len();
+        vs[k]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
 }
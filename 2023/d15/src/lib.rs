@@ -0,0 +1,37 @@
+use std::fmt::Display;
+use utils::solution::Solution;
+
+fn hash(s: &str) -> usize {
+    let mut sum = 0;
+    for c in s.chars() {
+        let ic = c as usize;
+        sum += ic;
+        sum = 17 * sum;
+        sum = sum % 256;
+    }
+    sum
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// initialization sequence steps
+    type Input = Vec<String>;
+
+    const SOLVED: [bool; 2] = [true, false];
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().split(',').map(String::from).collect()
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        input.iter().map(|step| hash(step)).sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+}
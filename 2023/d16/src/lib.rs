@@ -1,13 +1,5 @@
-use std::{io::Read, ops::Range, time::Duration};
-use utils::asciimap::AsciiMap;
-
-fn main() -> Result<(), std::io::Error> {
-    let mut input = "".into();
-    std::fs::File::open("input")?.read_to_string(&mut input)?;
-    //dbg!(puzzle1(&input));
-    //dbg!(puzzle2(&input));
-    Ok(())
-}
+use std::fmt::Display;
+use utils::{asciimap::AsciiMap, solution::Solution};
 
 #[derive(Debug, Copy, Clone)]
 enum Direction {
@@ -103,6 +95,7 @@ impl LavaFloor {
         Self { map, energized_map }
     }
 
+    #[cfg(test)]
     fn from_input(input: &str) -> Self {
         let map = AsciiMap::from_multi_lines(input);
         Self::new(map)
@@ -173,9 +166,7 @@ impl LavaFloor {
     }
 }
 
-pub fn find_highest_energy(input: &str) -> usize {
-    // load map
-    let map = AsciiMap::from_multi_lines(input);
+pub fn find_highest_energy(map: &AsciiMap) -> usize {
     let (w, h) = map.size();
     let w = w as isize;
     let h = h as isize;
@@ -196,7 +187,7 @@ pub fn find_highest_energy(input: &str) -> usize {
                 let nrj = lf.energized();
 
                 maxnrj = nrj.max(maxnrj);
-                eprintln!("{x} {y} {nrj}");
+                utils::debug!("{x} {y} {nrj}");
             }
         }
     }
@@ -204,6 +195,27 @@ pub fn find_highest_energy(input: &str) -> usize {
     maxnrj
 }
 
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = AsciiMap;
+
+    fn parse(input: &str) -> Self::Input {
+        AsciiMap::from_multi_lines(input.trim())
+    }
+
+    fn part1(map: &Self::Input) -> impl Display {
+        let mut lf = LavaFloor::new(map.clone());
+        // emit starts top-left corner going right
+        lf.emit_beam_xyd((0, 0), Direction::Right);
+        lf.energized()
+    }
+
+    fn part2(map: &Self::Input) -> impl Display {
+        find_highest_energy(map)
+    }
+}
+
 #[cfg(test)]
 mod test {
 
@@ -293,30 +305,8 @@ mod test {
 .|....-|.\\
 ..//.|....";
         //
-        assert_eq!(find_highest_energy(&input), 51);
-    }
-
-    #[test]
-    fn good_part1() {
-        let mut input = "".into();
-        std::fs::File::open("input")
-            .unwrap()
-            .read_to_string(&mut input)
-            .unwrap();
-        let mut lf = LavaFloor::from_input(&input.trim());
-        // emit starts top-left corner going right
-        lf.emit_beam_xyd((0, 0), Direction::Right);
-        // count energized tiles
-        assert_eq!(lf.energized() as u64, 7060);
+        assert_eq!(find_highest_energy(&Puzzle::parse(input)), 51);
     }
 
-    #[test]
-    fn good_part2() {
-        let mut input = "".into();
-        std::fs::File::open("input")
-            .unwrap()
-            .read_to_string(&mut input)
-            .unwrap();
-        assert_eq!(find_highest_energy(&input.trim()), 7493);
-    }
+    utils::answer_tests!(Puzzle; 2023, 16);
 }
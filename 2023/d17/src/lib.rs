@@ -0,0 +1,302 @@
+use std::fmt::Display;
+use utils::{asciimap::AsciiMap, search, solution::Solution};
+
+#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+enum Dir {
+    Up = 0,
+    Right = 1,
+    Down = 2,
+    Left = 3,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct CrucibleState {
+    pub x: isize,
+    pub y: isize,
+    pub dir: Dir,
+    /// tiles moved straight ahead since last turn
+    pub run: u8,
+}
+
+impl CrucibleState {
+    fn new(x: isize, y: isize, dir: Dir) -> Self {
+        Self { x, y, dir, run: 0 }
+    }
+
+    /// Return grid distance to provided position
+    fn distance(&self, x: isize, y: isize) -> usize {
+        (isize::abs(x - self.x) + isize::abs(y - self.y)) as usize
+    }
+
+    /// turn crucible by x increments of 90° (+1) to turn right (-1) to turn left
+    fn rotate(mut self, x: isize) -> Self {
+        let x = x as i8;
+        // enum are declared in order of positive rotations
+        let n = if x >= 0 {
+            u8::wrapping_add(self.dir as u8, x as u8) % 4
+        } else {
+            u8::wrapping_sub(self.dir as u8, -x as u8) % 4
+        };
+        // SAFETY: n is ensured to be in range of enum
+        self.dir = unsafe { std::mem::transmute(n) };
+        self.run = 0;
+
+        self
+    }
+
+    /// advance forward by 1
+    fn advance(mut self) -> Self {
+        match self.dir {
+            Dir::Left => self.x -= 1,
+            Dir::Right => self.x += 1,
+            Dir::Up => self.y -= 1,
+            Dir::Down => self.y += 1,
+        }
+        self.run += 1;
+        self
+    }
+
+    /// return heat at current state
+    fn heat(&self, map: &AsciiMap) -> Option<usize> {
+        let c = map.iget(self.x, self.y)?;
+        Some(c.to_digit(10).unwrap() as usize)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum CrucibleType {
+    Normal,
+    Ultra,
+}
+
+impl CrucibleType {
+    /// (min, max) tiles crucible moves straight ahead before turning or stopping
+    fn runs(&self) -> (u8, u8) {
+        match self {
+            CrucibleType::Normal => (1, 3),
+            CrucibleType::Ultra => (4, 10),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Node {
+    pub state: CrucibleState,
+    pub heat: usize,
+}
+
+impl Node {
+    fn new(state: CrucibleState, heat: usize) -> Self {
+        Self { state, heat }
+    }
+
+    /// return a node advanced with updated heat, return None if node is out of bounds
+    fn advance(&self, map: &AsciiMap) -> Option<Self> {
+        let ns = self.state.advance();
+        Some(Self::new(ns, self.heat + ns.heat(map)?))
+    }
+
+    /// return a vec of reachable nodes: turn left or right, then move straight
+    /// ahead as many tiles as crucible may before turning again
+    fn reachable(&self, map: &AsciiMap, ctype: CrucibleType) -> Vec<Node> {
+        let (min, max) = ctype.runs();
+        let mut nodes = vec![];
+        for r in [-1, 1] {
+            let mut next_node = Self::new(self.state.rotate(r), self.heat);
+            for _ in 0..max {
+                let Some(node) = next_node.advance(map) else {
+                    break;
+                };
+                next_node = node;
+                if next_node.state.run >= min {
+                    nodes.push(next_node);
+                }
+            }
+        }
+
+        nodes
+    }
+}
+
+/// No path leads crucible to the target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unreachable;
+
+impl Display for Unreachable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "target is not reachable")
+    }
+}
+
+impl std::error::Error for Unreachable {}
+
+pub struct LavaMap {
+    map: AsciiMap,
+}
+
+impl LavaMap {
+    fn new(map: AsciiMap) -> Self {
+        Self { map }
+    }
+
+    fn from_input(input: &str) -> Self {
+        let map = AsciiMap::from_multi_lines(input);
+        Self::new(map)
+    }
+
+    fn solve(&self, ctype: CrucibleType) -> Result<usize, Unreachable> {
+        let target = (
+            self.map.width() as isize - 1,
+            self.map.height() as isize - 1,
+        );
+
+        // crucible starts in
+        // top-left corner facing right
+        // or top-left corner facing down
+        // NOTE: starting tile heat is not taken into account
+        // crucible turns first, so it may leave facing any direction
+        let starts = [
+            CrucibleState::new(0, 0, Dir::Right),
+            CrucibleState::new(0, 0, Dir::Down),
+        ];
+        self.map.print();
+
+        // every tile costs at least 1 heat, so remaining distance never
+        // overestimates remaining heat
+        let (min, _) = ctype.runs();
+        let found = search::astar(
+            starts,
+            |&state| {
+                utils::trace!("{state:?}");
+                Node::new(state, 0)
+                    .reachable(&self.map, ctype)
+                    .into_iter()
+                    .map(|node| (node.state, node.heat))
+            },
+            |state| state.distance(target.0, target.1),
+            // crucible can only stop after moving enough straight ahead
+            |state| state.distance(target.0, target.1) == 0 && state.run >= min,
+        )
+        .ok_or(Unreachable)?;
+        utils::debug!("{} states expanded", found.expanded);
+
+        Ok(found.cost)
+    }
+}
+
+/// heat loss, or why there is none
+fn answer(loss: Result<usize, Unreachable>) -> String {
+    match loss {
+        Ok(heat) => heat.to_string(),
+        Err(err) => err.to_string(),
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = LavaMap;
+
+    fn parse(input: &str) -> Self::Input {
+        LavaMap::from_input(input.trim())
+    }
+
+    fn part1(map: &Self::Input) -> impl Display {
+        answer(map.solve(CrucibleType::Normal))
+    }
+
+    fn part2(map: &Self::Input) -> impl Display {
+        answer(map.solve(CrucibleType::Ultra))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn rotations() {
+        let mut node = CrucibleState::new(0, 0, Dir::Right);
+        node = node.rotate(1);
+        assert_eq!(node.dir, Dir::Down);
+        node = node.rotate(1);
+        assert_eq!(node.dir, Dir::Left);
+        node = node.rotate(1);
+        assert_eq!(node.dir, Dir::Up);
+        node = node.rotate(1);
+        assert_eq!(node.dir, Dir::Right);
+
+        let mut node = CrucibleState::new(0, 0, Dir::Right);
+        node = node.rotate(4);
+        assert_eq!(node.dir, Dir::Right);
+
+        let mut node = CrucibleState::new(0, 0, Dir::Right);
+        node = node.rotate(-1);
+        assert_eq!(node.dir, Dir::Up);
+        node = node.rotate(-1);
+        assert_eq!(node.dir, Dir::Left);
+        node = node.rotate(-1);
+        assert_eq!(node.dir, Dir::Down);
+        node = node.rotate(-1);
+        assert_eq!(node.dir, Dir::Right);
+    }
+
+    #[test]
+    fn advances() {
+        let mut node = CrucibleState::new(0, 0, Dir::Right);
+        node = node.advance();
+        assert_eq!((node.x, node.y, node.dir, node.run), (1, 0, Dir::Right, 1));
+        node = node.advance();
+        assert_eq!((node.x, node.y, node.dir, node.run), (2, 0, Dir::Right, 2));
+        // turning starts a new run
+        assert_eq!(node.rotate(1).run, 0);
+
+        let mut node = CrucibleState::new(0, 0, Dir::Down);
+        node = node.advance();
+        assert_eq!((node.x, node.y, node.dir, node.run), (0, 1, Dir::Down, 1));
+    }
+
+    #[test]
+    fn reachable() {
+        let input = "111\n111\n111\n111\n111";
+        let map = AsciiMap::from_multi_lines(input);
+
+        let state = |x, y, dir, run| CrucibleState { x, y, dir, run };
+        let node = Node::new(CrucibleState::new(1, 1, Dir::Right), 10);
+        let rs = node.reachable(&map, CrucibleType::Normal);
+        assert_eq!(
+            rs,
+            vec![
+                Node::new(state(1, 0, Dir::Up, 1), 11),
+                Node::new(state(1, 2, Dir::Down, 1), 11),
+                Node::new(state(1, 3, Dir::Down, 2), 12),
+                Node::new(state(1, 4, Dir::Down, 3), 13),
+            ]
+        );
+
+        // ultra crucible moves at least 4 tiles before turning
+        let map = AsciiMap::from_multi_lines("1111111111111\n1111111111111");
+        let node = Node::new(CrucibleState::new(0, 0, Dir::Down), 0);
+        let rs = node.reachable(&map, CrucibleType::Ultra);
+        assert_eq!(rs.len(), 7);
+        assert_eq!(rs[0], Node::new(state(4, 0, Dir::Right, 4), 4));
+        assert_eq!(rs[6], Node::new(state(10, 0, Dir::Right, 10), 10));
+    }
+
+    #[test]
+    fn heat() {
+        let input = "123\n456";
+        let map = AsciiMap::from_multi_lines(input);
+        assert_eq!(CrucibleState::new(0, 0, Dir::Down).heat(&map), Some(1));
+        assert_eq!(CrucibleState::new(1, 0, Dir::Down).heat(&map), Some(2));
+        assert_eq!(CrucibleState::new(0, 1, Dir::Down).heat(&map), Some(4));
+        assert_eq!(CrucibleState::new(2, 1, Dir::Down).heat(&map), Some(6));
+        assert_eq!(CrucibleState::new(3, 1, Dir::Down).heat(&map), None);
+        assert_eq!(CrucibleState::new(0, 2, Dir::Down).heat(&map), None);
+    }
+
+    utils::example_tests!(Puzzle; example1, example2, example3);
+
+    utils::answer_tests!(Puzzle; 2023, 17);
+}
@@ -0,0 +1,105 @@
+use std::fmt::Display;
+use utils::solution::Solution;
+
+#[derive(Debug)]
+struct Set {
+    pub r: u32,
+    pub g: u32,
+    pub b: u32,
+}
+
+impl Set {
+    pub fn check(&self, br: u32, bg: u32, bb: u32) -> bool {
+        (self.r <= br) && (self.g <= bg) && (self.b <= bb)
+    }
+
+    pub fn power(&self) -> u32 {
+        self.r * self.g * self.b
+    }
+}
+
+fn minimum_set(vset: &Vec<Set>) -> Set {
+    let mut r = 0;
+    let mut g = 0;
+    let mut b = 0;
+    for set in vset {
+        r = u32::max(r, set.r);
+        g = u32::max(g, set.g);
+        b = u32::max(b, set.b);
+    }
+    Set { r, g, b }
+}
+
+pub struct Game {
+    id: u32,
+    sets: Vec<Set>,
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut games = vec![];
+        for line in input.split('\n') {
+            if line.len() == 0 {
+                continue;
+            }
+            let mut line = line.split(':');
+
+            // extract game number
+            let game = line.next().unwrap().trim();
+            let game = game.strip_prefix("Game ").unwrap().trim();
+            let id = u32::from_str_radix(game, 10).unwrap();
+
+            // extract sets
+            let sets = line.next().unwrap();
+            let mut csets = vec![];
+            for set in sets.split(';') {
+                let mut cset = Set { r: 0, g: 0, b: 0 };
+                for color in set.split(',') {
+                    let color = color.trim();
+                    let mut color = color.split(' ');
+
+                    let n = color.next().unwrap();
+                    let n = u32::from_str_radix(n, 10).unwrap();
+                    let color = color.next().unwrap();
+
+                    match color {
+                        "blue" => cset.b = n,
+                        "red" => cset.r = n,
+                        "green" => cset.g = n,
+                        _ => panic!("unknown color {color}"),
+                    }
+                }
+                csets.push(cset);
+            }
+            games.push(Game { id, sets: csets });
+        }
+        games
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        // sum possible games ID
+        input
+            .iter()
+            .filter(|game| game.sets.iter().all(|set| set.check(12, 13, 14)))
+            .map(|game| game.id)
+            .sum::<u32>()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        input
+            .iter()
+            .map(|game| minimum_set(&game.sets).power())
+            .sum::<u32>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+}
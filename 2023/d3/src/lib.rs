@@ -1,6 +1,9 @@
 use std::collections::VecDeque;
+use std::fmt::Display;
+use utils::solution::Solution;
 
-struct Schematic {
+#[derive(Clone)]
+pub struct Schematic {
     matrix: Vec<Vec<char>>,
     width: isize,
     height: isize,
@@ -55,10 +58,11 @@ impl Schematic {
         }
     }
 
+    #[allow(unused)]
     pub fn print(&self) {
         for line in &self.matrix {
             let line: String = line.iter().collect();
-            println!("{}", line);
+            utils::debug!("{}", line);
         }
     }
 
@@ -112,7 +116,9 @@ impl Schematic {
             if let Some(c) = self.get(x, y) {
                 if c.is_numeric() {
                     // we found a part number
-                    self.extract_part_number(x, y);
+                    if let Some(pn) = self.extract_part_number(x, y) {
+                        self.sum += pn;
+                    }
                 }
             }
         }
@@ -167,7 +173,6 @@ impl Schematic {
             for x in 0..self.width {
                 if let Some(c) = self.get(x, y) {
                     if self.is_possible_gear(c) {
-                        println!("{x}:{y} = {c}");
                         self.check_gear(x, y);
                     }
                 }
@@ -181,7 +186,6 @@ impl Schematic {
             for x in 0..self.width {
                 if let Some(c) = self.get(x, y) {
                     if self.is_symbol(c) {
-                        println!("{x}:{y} = {c}");
                         self.find_associated_tag(x, y);
                     }
                 }
@@ -190,16 +194,31 @@ impl Schematic {
     }
 }
 
-use std::io::Read;
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Self::Input {
+        Schematic::from(input)
+    }
 
-fn main() {
-    let mut file = std::fs::File::open("input").unwrap();
-    let mut input = String::new();
-    file.read_to_string(&mut input).unwrap();
+    fn part1(input: &Self::Input) -> impl Display {
+        let mut s = input.clone();
+        s.process_part1();
+        s.sum()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let mut s = input.clone();
+        s.process_part2();
+        s.sum()
+    }
+}
 
-    let mut s = Schematic::from(&input);
-    s.print();
-    s.process_part2();
+#[cfg(test)]
+mod test {
+    use super::*;
 
-    println!("sum={}", s.sum());
+    utils::example_tests!(Puzzle; example);
 }
@@ -0,0 +1,95 @@
+use std::fmt::Display;
+use utils::solution::Solution;
+
+fn numbers_from_str(s: &str) -> Vec<u32> {
+    s.split(' ')
+        .filter_map(|s| u32::from_str_radix(s, 10).ok())
+        .collect()
+}
+
+pub struct Card {
+    win: Vec<u32>,
+    own: Vec<u32>,
+}
+
+impl Card {
+    /// number of own numbers which are winning numbers
+    fn matches(&self) -> usize {
+        self.own.iter().filter(|on| self.win.contains(on)).count()
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut cards = vec![];
+        for line in input.split('\n') {
+            if line.len() == 0 {
+                continue;
+            }
+
+            // -- extract card number
+            let mut line = line.split(':');
+            let _card = line.next().unwrap();
+
+            // -- extract winning numbers
+            let line = line.next().unwrap();
+            let mut line = line.split('|');
+            let win = line.next().unwrap();
+            let win = numbers_from_str(win);
+
+            // -- extract own numbers
+            let own = line.next().unwrap();
+            let own = numbers_from_str(own);
+
+            cards.push(Card { win, own });
+        }
+        cards
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        let mut sum: u32 = 0;
+        for card in input {
+            // -- compute card score
+            let mut score = 0;
+            for _ in 0..card.matches() {
+                if score == 0 {
+                    score = 1;
+                } else {
+                    score *= 2;
+                }
+            }
+            sum += score;
+        }
+        sum
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let mut copies = Vec::new();
+        copies.resize(input.len(), 0u32);
+
+        let mut sum: u32 = 0;
+        for (i, card) in input.iter().enumerate() {
+            // -- count cards
+            let inc = 1 + copies[i];
+            sum += inc;
+
+            // -- append copies of cards to stack
+            for k in 0..card.matches() {
+                let idx = i + k + 1;
+                copies[idx] += inc;
+            }
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+}
@@ -1,69 +0,0 @@
-fn numbers_from_str(s: &str) -> Vec<u32> {
-    s.split(' ')
-        .filter_map(|s| u32::from_str_radix(s, 10).ok())
-        .collect()
-}
-
-fn main() {
-    /*
-    let data = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-    Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-    Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-    Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-    Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-    Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
-    */
-    let mut file = std::fs::File::open("input").unwrap();
-    use std::io::Read;
-    let mut data = String::new();
-    file.read_to_string(&mut data).unwrap();
-
-    let cards: Vec<&str> = data.split('\n').collect();
-    let n = cards.len();
-
-    let mut copies = Vec::new();
-    copies.resize(n, 0u32);
-
-    let mut sum: u32 = 0;
-    for (i, line) in cards.iter().enumerate() {
-        if line.len() == 0 {
-            continue;
-        }
-
-        // -- extract card number
-        let mut line = line.split(':');
-        let _card = line.next().unwrap();
-        println!("{_card}");
-
-        // -- extract winning numbers
-        let line = line.next().unwrap();
-        let mut line = line.split('|');
-        let win = line.next().unwrap();
-        let win = numbers_from_str(win);
-
-        // -- extract own numbers
-        let own = line.next().unwrap();
-        let own = numbers_from_str(own);
-
-        // -- compute card score
-        let mut score = 0;
-        for on in own {
-            if win.contains(&on) {
-                score += 1;
-            }
-        }
-
-        // -- count cards
-        let inc = 1 + copies[i];
-        println!("count += {inc}");
-        sum += inc;
-
-        // -- append copies of cards to stack
-        for k in 0..score {
-            let idx = i + k + 1;
-            println!("{idx}");
-            copies[idx] += inc;
-        }
-    }
-    println!("TOTAL {sum}");
-}
@@ -0,0 +1,172 @@
+use std::fmt::Display;
+use utils::piecewise::PiecewiseMap;
+use utils::solution::Solution;
+
+#[repr(C)]
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+enum Category {
+    Seed = 0,
+    Soil,
+    Fertilizer,
+    Water,
+    Light,
+    Temperature,
+    Humidity,
+    Location,
+}
+
+impl Category {
+    pub fn from_str(s: &str) -> Category {
+        match s {
+            "seed" => Category::Seed,
+            "soil" => Category::Soil,
+            "fertilizer" => Category::Fertilizer,
+            "water" => Category::Water,
+            "light" => Category::Light,
+            "temperature" => Category::Temperature,
+            "humidity" => Category::Humidity,
+            "location" => Category::Location,
+            _ => panic!("unknown category: {s}"),
+        }
+    }
+}
+
+fn numbers_from_str(s: &str) -> Vec<i64> {
+    s.split(' ')
+        .filter_map(|s| i64::from_str_radix(s, 10).ok())
+        .collect()
+}
+
+#[derive(Clone, Debug)]
+struct SourceDestinationMap {
+    destination: Category,
+    map: PiecewiseMap<i64>,
+}
+
+impl SourceDestinationMap {
+    pub fn new(destination: Category) -> Self {
+        Self {
+            destination,
+            map: PiecewiseMap::new(),
+        }
+    }
+    pub fn destination(&self) -> Category {
+        self.destination
+    }
+
+    pub fn add_range(&mut self, didx: i64, sidx: i64, range: i64) {
+        self.map.insert(sidx..sidx + range, didx)
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    /// conversion maps, indexed by source category
+    maps: Vec<Option<SourceDestinationMap>>,
+}
+
+impl Almanac {
+    /// chain every map from seeds down to locations into a single one
+    fn seed_to_location(&self) -> PiecewiseMap<i64> {
+        let mut map = PiecewiseMap::new();
+        let mut source = Category::Seed;
+        while source != Category::Location {
+            let sdmap = self.maps[source as usize].as_ref().unwrap();
+            map = map.then(&sdmap.map);
+            source = sdmap.destination();
+        }
+        map
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut lines = input.split('\n');
+
+        // -- extract seeds
+        let seeds = lines.next().unwrap();
+
+        let seeds = seeds.strip_prefix("seeds:").unwrap();
+        let seeds = numbers_from_str(seeds);
+
+        // pop an empty line
+        lines.next().unwrap();
+
+        let mut maps = Vec::new();
+        maps.resize(8, None);
+
+        // -- extract maps
+        let mut run = true;
+        while run {
+            // A-to-B map:
+            let line = lines.next();
+
+            if line.is_none() {
+                break;
+            }
+            let line = line.unwrap();
+
+            let line = line.strip_suffix(" map:").unwrap();
+            let mut ab = line.split("-to-");
+            let source = ab.next().unwrap();
+            let destination = ab.next().unwrap();
+
+            let mut sdmap = SourceDestinationMap::new(Category::from_str(destination));
+
+            // range lines
+            loop {
+                let line = lines.next();
+                if let Some(line) = line {
+                    if line.len() == 0 {
+                        // empty line poped
+                        break;
+                    }
+                    let vs = numbers_from_str(line);
+                    sdmap.add_range(vs[0], vs[1], vs[2]);
+                } else {
+                    // EOF
+                    run = false;
+                    break;
+                }
+            }
+
+            maps[Category::from_str(source) as usize] = Some(sdmap);
+        }
+
+        Almanac { seeds, maps }
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        let location = input.seed_to_location();
+        // for each initial seed
+        input
+            .seeds
+            .iter()
+            .map(|&seed| location.map(seed))
+            .min()
+            .unwrap()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let location = input.seed_to_location();
+        // seeds are given as (start, length) ranges, map whole ranges at once
+        input
+            .seeds
+            .chunks(2)
+            .flat_map(|range| location.map_range(range[0]..range[0] + range[1]))
+            .map(|range| range.start)
+            .min()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+}
@@ -0,0 +1,65 @@
+use std::fmt::Display;
+use utils::num;
+use utils::solution::Solution;
+
+/// number of button press durations beating the record distance
+fn race(duration: u128, distance: u128) -> u128 {
+    // holding the button x ms travels x*(duration - x)
+    num::count_product_above(duration, distance)
+}
+
+/// read numbers following the line label (e.g. `Time:`)
+fn numbers(line: &str) -> Vec<u128> {
+    let (_, values) = line.split_once(':').unwrap();
+    values
+        .split_whitespace()
+        .map(|s| s.parse().unwrap())
+        .collect()
+}
+
+/// read numbers as a single number, ignoring spaces between them
+fn kerned_number(numbers: &[u128]) -> u128 {
+    let digits: String = numbers.iter().map(|n| n.to_string()).collect();
+    digits.parse().unwrap()
+}
+
+pub struct Races {
+    durations: Vec<u128>,
+    distances: Vec<u128>,
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Races;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut lines = input.lines();
+        let durations = numbers(lines.next().unwrap());
+        let distances = numbers(lines.next().unwrap());
+        Races {
+            durations,
+            distances,
+        }
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        std::iter::zip(&input.durations, &input.distances)
+            .map(|(&duration, &distance)| race(duration, distance))
+            .product::<u128>()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        race(
+            kerned_number(&input.durations),
+            kerned_number(&input.distances),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+}
@@ -1,5 +1,7 @@
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use std::cmp::{Ord, Ordering, PartialEq, PartialOrd, Reverse};
+use std::fmt::Display;
+use utils::solution::Solution;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
@@ -91,7 +93,7 @@ enum HandType {
 }
 
 #[derive(Debug)]
-struct Hand {
+pub struct Hand {
     cards: Vec<Card>,
 }
 
@@ -114,7 +116,7 @@ impl Hand {
         Ordering::Equal
     }
 
-    pub fn get_hand_type(&self) -> HandType {
+    fn get_hand_type(&self) -> HandType {
         let bucket = CardBucket::from(&self.cards);
 
         let ht = match (bucket.nth(0), bucket.nth(1)) {
@@ -182,59 +184,64 @@ impl Ord for Hand {
     }
 }
 
-fn main() {
-    /*
-    let data = "32T3K 765
-    T55J5 684
-    KK677 28
-    KTJJT 220
-    QQQJA 483";
-    */
-
-    let mut file = std::fs::File::open("input").unwrap();
-    let mut data = String::new();
-    use std::io::Read;
-    file.read_to_string(&mut data).unwrap();
-
-    // -- quick testing
-    assert!(Card::N2 < Card::N3);
-    assert!(Card::N2 < Card::A);
-    assert!(Card::N8 == Card::N8);
-
-    assert!(Hand::from_str("22222") == Hand::from_str("22222"));
-    assert!(Hand::from_str("33333") > Hand::from_str("22222"));
-    assert!(Hand::from_str("22222") > Hand::from_str("22223"));
-
-    let mut hands = vec![];
-    for line in data.split('\n') {
-        let line = line.trim();
-        if line.len() == 0 {
-            continue;
-        }
-        let mut line = line.split(' ');
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// (hand, bid) pairs
+    type Input = Vec<(Hand, u32)>;
+
+    const SOLVED: [bool; 2] = [false, true];
+
+    fn parse(input: &str) -> Self::Input {
+        let mut hands = vec![];
+        for line in input.split('\n') {
+            let line = line.trim();
+            if line.len() == 0 {
+                continue;
+            }
+            let mut line = line.split(' ');
+
+            // read hand
+            let hand = line.next().unwrap();
+            let hand = Hand::from_str(hand);
+
+            // read hand score
+            let score = line.next().unwrap();
+            let score = u32::from_str_radix(score, 10).unwrap();
 
-        // read hand
-        let hand = line.next().unwrap();
-        let hand = Hand::from_str(hand);
-        println!("{hand:?}");
-        let ht = hand.get_hand_type();
-        println!("{ht:?}");
+            hands.push((hand, score));
+        }
+        hands
+    }
 
-        // read hand score
-        let score = line.next().unwrap();
-        let score = u32::from_str_radix(score, 10).unwrap();
+    // NOTE cards rank with 'J' as joker, part one rules are gone
+    fn part2(input: &Self::Input) -> impl Display {
+        // rank hands
+        let mut hands: Vec<&(Hand, u32)> = input.iter().collect();
+        hands.sort_by(|lhs, rhs| lhs.0.cmp(&rhs.0));
 
-        hands.push((hand, score));
+        hands
+            .iter()
+            .enumerate()
+            .map(|(i, (_, score))| (i as u32 + 1) * score)
+            .sum::<u32>()
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
 
-    // rank hands
-    hands.sort_by(|lhs, rhs| lhs.0.cmp(&rhs.0));
+    #[test]
+    fn ordering() {
+        assert!(Card::N2 < Card::N3);
+        assert!(Card::N2 < Card::A);
+        assert!(Card::N8 == Card::N8);
 
-    let sum: u32 = hands
-        .iter()
-        .enumerate()
-        .map(|(i, (_, score))| (i as u32 + 1) * score)
-        .sum();
+        assert!(Hand::from_str("22222") == Hand::from_str("22222"));
+        assert!(Hand::from_str("33333") > Hand::from_str("22222"));
+        assert!(Hand::from_str("22222") > Hand::from_str("22223"));
+    }
 
-    println!("{sum:?}");
+    utils::example_tests!(Puzzle; example);
 }
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use utils::graph::Graph;
+use utils::num;
+use utils::solution::Solution;
+
+pub struct Network {
+    /// instructions, as edge labels: left is 0, right is 1
+    path: Vec<usize>,
+    /// junctions are nodes, left edge is labelled 0, right edge is labelled 1
+    map: Graph,
+}
+
+/// Times a ghost stands on a Z junction
+struct Ghost {
+    /// hits before ghost enters its cycle
+    before: Vec<i128>,
+    /// time ghost enters its cycle
+    start: i128,
+    /// cycle length
+    period: i128,
+    /// hits during first cycle, repeating every period steps
+    hits: Vec<i128>,
+}
+
+impl Ghost {
+    /// follow instructions from junction until ghost state (junction,
+    /// instruction index) repeats
+    fn new(network: &Network, mut pos: usize) -> Self {
+        let map = &network.map;
+        let mut seen = HashMap::new();
+        let mut z_hits = vec![];
+        let mut t = 0i128;
+        loop {
+            let instruction = t as usize % network.path.len();
+            if let Some(&start) = seen.get(&(pos, instruction)) {
+                let (before, hits) = z_hits.iter().partition(|&&hit| hit < start);
+                return Self {
+                    before,
+                    start,
+                    period: t - start,
+                    hits,
+                };
+            }
+            seen.insert((pos, instruction), t);
+            if map.name(pos).ends_with('Z') {
+                z_hits.push(t);
+            }
+
+            // apply instruction
+            pos = map.successor(pos, &network.path[instruction]).unwrap();
+            t += 1;
+        }
+    }
+
+    fn is_on_z(&self, t: i128) -> bool {
+        if t < self.start {
+            self.before.contains(&t)
+        } else {
+            let offset = (t - self.start) % self.period;
+            self.hits.contains(&(self.start + offset))
+        }
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Network;
+
+    const SOLVED: [bool; 2] = [false, true];
+
+    fn parse(input: &str) -> Self::Input {
+        let (path, map) = input.split_once('\n').unwrap();
+        let path = path
+            .trim()
+            .chars()
+            .map(|instruction| match instruction {
+                'L' => 0,
+                'R' => 1,
+                _ => panic!(),
+            })
+            .collect();
+
+        let map = Graph::from_adjacency_list(map).unwrap();
+
+        Network { path, map }
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let map = &input.map;
+
+        let ghosts: Vec<Ghost> = map
+            .nodes()
+            .filter(|&location| map.name(location).ends_with('A'))
+            .map(|location| Ghost::new(input, location))
+            .collect();
+
+        // hits before a cycle happen once, check them directly
+        let early = ghosts
+            .iter()
+            .flat_map(|ghost| ghost.before.iter().copied())
+            .filter(|&t| ghosts.iter().all(|ghost| ghost.is_on_z(t)))
+            .min();
+
+        // once every ghost cycles, it is on a Z junction at t = hit (mod
+        // period) for one of its hits, solve for each combination of hits
+        let start = ghosts.iter().map(|ghost| ghost.start).max().unwrap_or(0);
+        let mut combinations = vec![(0, 1)];
+        for ghost in &ghosts {
+            combinations = combinations
+                .iter()
+                .flat_map(|&solution| {
+                    ghost
+                        .hits
+                        .iter()
+                        .filter_map(move |&hit| num::crt([solution, (hit, ghost.period)]))
+                })
+                .collect();
+        }
+        let cycling = combinations
+            .into_iter()
+            .map(|(t, period)| {
+                // smallest aligned time once every ghost cycles
+                if t < start {
+                    t + (start - t + period - 1) / period * period
+                } else {
+                    t
+                }
+            })
+            .min();
+
+        early
+            .into_iter()
+            .chain(cycling)
+            .min()
+            .expect("ghosts should align at some point")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+
+    fn part2(input: &str) -> String {
+        Puzzle::part2(&Puzzle::parse(input)).to_string()
+    }
+
+    #[test]
+    fn several_z_per_cycle() {
+        // first ghost is on 11Z every 3 steps, second one on 22Z or 2ZZ at
+        // t = 2 or 4 (mod 5): they first align at t = 9
+        let input = "L
+
+11A = (11B, 11B)
+11B = (11C, 11C)
+11C = (11Z, 11Z)
+11Z = (11B, 11B)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22C, 22C)
+22C = (2ZZ, 2ZZ)
+2ZZ = (22D, 22D)
+22D = (22B, 22B)";
+        assert_eq!(part2(input), "9");
+    }
+
+    #[test]
+    fn before_cycle() {
+        // first ghost is only once on a Z junction, before it loops on 11B
+        let input = "LR
+
+11A = (11Z, XXX)
+11Z = (XXX, 11B)
+11B = (11B, 11B)
+22A = (22Z, XXX)
+22Z = (22A, 22A)
+XXX = (XXX, XXX)";
+        assert_eq!(part2(input), "1");
+    }
+}
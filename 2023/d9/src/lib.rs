@@ -0,0 +1,45 @@
+use std::fmt::Display;
+use utils::polynomial::Polynomial;
+use utils::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Polynomial>;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut sequences = vec![];
+        for line in input.split('\n') {
+            let line = line.trim();
+            if line.len() == 0 {
+                continue;
+            }
+            let values: Vec<i64> = line.split(' ').map(|s| s.parse().unwrap()).collect();
+            sequences.push(Polynomial::fit(&values));
+        }
+        sequences
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        input
+            .iter()
+            .map(|p| p.next(1))
+            .sum::<Option<i128>>()
+            .expect("values should fit in an i128")
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        input
+            .iter()
+            .map(|p| p.previous(1))
+            .sum::<Option<i128>>()
+            .expect("values should fit in an i128")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+}
@@ -0,0 +1,53 @@
+use std::fmt::Display;
+use utils::solution::Solution;
+
+fn lists(input: &str) -> (Vec<i32>, Vec<i32>) {
+    let vs = utils::inputs::from_separated_values::<i32>(input);
+
+    // transpose vectors
+    let vs = utils::arrays::transpose(vs);
+
+    (vs[0].clone(), vs[1].clone())
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// (left list, right list)
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Self::Input {
+        lists(input)
+    }
+
+    fn part1((lvs, rvs): &Self::Input) -> impl Display {
+        let mut lvs = lvs.clone();
+        let mut rvs = rvs.clone();
+
+        // sort both vectors in place
+        lvs.sort();
+        rvs.sort();
+
+        // zip both sorted list, compute distance and sum
+        std::iter::zip(lvs, rvs)
+            .map(|(a, b)| (a - b).abs())
+            .sum::<i32>()
+    }
+
+    fn part2((lvs, rvs): &Self::Input) -> impl Display {
+        // compute similarity
+        lvs.iter()
+            .map(|&lv| {
+                let count = rvs.iter().filter(|&&rv| rv == lv).count();
+                lv * (count as i32)
+            })
+            .sum::<i32>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+}
@@ -1,3 +1,6 @@
+use std::fmt::Display;
+use utils::solution::Solution;
+
 fn test_all(v: &Vec<i32>, f: impl Fn(i32, i32) -> bool) -> bool {
     for idx in 0..v.len() - 1 {
         if !f(v[idx], v[idx + 1]) {
@@ -40,34 +43,32 @@ fn check_allow_one_bad(v: &Vec<i32>, test: impl Fn(&Vec<i32>) -> bool) -> bool {
     return false;
 }
 
-fn main() {
-    let input = "7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9";
+pub struct Puzzle;
 
-    let input = std::fs::read_to_string("./input").unwrap();
+impl Solution for Puzzle {
+    /// reports
+    type Input = Vec<Vec<i32>>;
 
-    let reports = utils::inputs::from_separated_values::<i32>(&input);
+    fn parse(input: &str) -> Self::Input {
+        utils::inputs::from_separated_values::<i32>(input)
+    }
 
-    // part one
-    let nsafes = reports
-        .iter()
-        .filter(|report| all_increasing(&report) || all_decreasing(&report))
-        .count();
-    println!("P1: {nsafes} safe reports");
+    fn part1(reports: &Self::Input) -> impl Display {
+        reports
+            .iter()
+            .filter(|report| all_increasing(&report) || all_decreasing(&report))
+            .count()
+    }
 
-    // part two
-    let nsafes = reports
-        .iter()
-        .filter(|report| {
-            check_allow_one_bad(&report, all_increasing)
-                || check_allow_one_bad(&report, all_decreasing)
-        })
-        .count();
-    println!("P1: {nsafes} safe reports");
+    fn part2(reports: &Self::Input) -> impl Display {
+        reports
+            .iter()
+            .filter(|report| {
+                check_allow_one_bad(&report, all_increasing)
+                    || check_allow_one_bad(&report, all_decreasing)
+            })
+            .count()
+    }
 }
 
 #[cfg(test)]
@@ -93,3 +94,10 @@ mod tests {
         assert_eq!(all_decreasing(&vec![5, 4, 5, 2, 1]), false);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+}
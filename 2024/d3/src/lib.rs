@@ -0,0 +1,64 @@
+use regex::Regex;
+use std::fmt::Display;
+use utils::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// corrupted memory
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.to_string()
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        let re = Regex::new(r"mul\(([0-9]+),([0-9]+)\)").unwrap();
+        re.captures_iter(input)
+            .map(|m| {
+                let lv: i32 = m.get(1).unwrap().as_str().parse().unwrap();
+                let rv: i32 = m.get(2).unwrap().as_str().parse().unwrap();
+                lv * rv
+            })
+            .sum::<i32>()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        enabled_sum(input)
+    }
+}
+
+/// sum of multiplications, skipping those disabled by `don't()`
+fn enabled_sum(input: &str) -> i32 {
+    let re = Regex::new(r"(?:do\(\)|don't\(\)|mul\(([0-9]+),([0-9]+)\))").unwrap();
+
+    let mut sum = 0;
+    let mut mul_enabled = true;
+    for m in re.captures_iter(input) {
+        let fm = m.get(0).unwrap().as_str();
+        if fm.starts_with("do()") {
+            mul_enabled = true;
+        } else if fm.starts_with("don't()") {
+            mul_enabled = false;
+        } else {
+            if mul_enabled {
+                let lv: i32 = m.get(1).unwrap().as_str().parse().unwrap();
+                let rv: i32 = m.get(2).unwrap().as_str().parse().unwrap();
+                sum += lv * rv
+            }
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests!(Puzzle; example1, example2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+}
@@ -1,4 +1,5 @@
-use utils::asciimap::AsciiMap;
+use std::fmt::Display;
+use utils::{asciimap::AsciiMap, solution::Solution};
 
 fn check_ray(map: &AsciiMap, center: (isize, isize), increment: (isize, isize)) -> bool {
     let (mut x, mut y) = center;
@@ -30,22 +31,25 @@ fn check_x_mas(map: &AsciiMap, center: (usize, usize)) -> Option<()> {
         .then_some(())
 }
 
-fn main() {
-    let input = "MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX";
-    let input = std::fs::read_to_string("./input").unwrap();
+pub struct Puzzle;
 
-    let map = AsciiMap::from_multi_lines(&input);
+impl Solution for Puzzle {
+    type Input = AsciiMap;
 
-    // -- part one --
+    fn parse(input: &str) -> Self::Input {
+        AsciiMap::from_multi_lines(input)
+    }
+
+    fn part1(map: &Self::Input) -> impl Display {
+        count_xmas(map)
+    }
+
+    fn part2(map: &Self::Input) -> impl Display {
+        count_x_mas(map)
+    }
+}
+
+fn count_xmas(map: &AsciiMap) -> usize {
     // iterate through map pixels
     let mut found = 0;
     for y in 0..map.height() {
@@ -64,16 +68,17 @@ MXMXAXMASX";
                     (0, -1),
                     (1, -1),
                 ] {
-                    if check_ray(&map, (x as isize, y as isize), increment) {
+                    if check_ray(map, (x as isize, y as isize), increment) {
                         found += 1;
                     }
                 }
             }
         }
     }
-    println!("P1: {found} XMAS found");
+    found
+}
 
-    // -- part two --
+fn count_x_mas(map: &AsciiMap) -> usize {
     let mut found = 0;
     for y in 0..map.height() {
         for x in 0..map.width() {
@@ -81,11 +86,18 @@ MXMXAXMASX";
             // X-MAS top-left pixel is a 'M'
             let c = map.get(x, y).unwrap();
             if *c == 'A' {
-                if check_x_mas(&map, (x, y)).is_some() {
+                if check_x_mas(map, (x, y)).is_some() {
                     found += 1;
                 }
             }
         }
     }
-    println!("P2: {found} XMAS found");
+    found
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
 }
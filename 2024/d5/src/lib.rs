@@ -0,0 +1,55 @@
+use std::fmt::Display;
+use utils::graph::Graph;
+use utils::order::Precedence;
+use utils::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// (ordering rules, updates)
+    type Input = (Precedence<i32>, Vec<Vec<i32>>);
+
+    fn parse(input: &str) -> Self::Input {
+        let (rules, updates) = input.split_once("\n\n").unwrap();
+        // ordering rules, edges going from each page to one it must precede
+        let graph = Graph::from_edge_list(rules, '|').unwrap();
+        let rules = Precedence::from_graph(&graph, |page| page.parse().unwrap());
+
+        let updates = updates
+            .lines()
+            .map(|line| line.split(',').map(|x| x.parse().unwrap()).collect())
+            .collect();
+
+        (rules, updates)
+    }
+
+    fn part1((rules, updates): &Self::Input) -> impl Display {
+        let mut result = 0;
+        for update in updates {
+            if rules.is_ordered(update) {
+                // find middle page and sum value
+                result += update[update.len() / 2]
+            }
+        }
+        result
+    }
+
+    fn part2((rules, updates): &Self::Input) -> impl Display {
+        let mut result = 0;
+        for update in updates {
+            if !rules.is_ordered(update) {
+                // invalid order
+                let nup = rules.topological_sort(update).unwrap();
+                result += nup[nup.len() / 2]
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+}
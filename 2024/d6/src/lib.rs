@@ -1,6 +1,7 @@
+use std::fmt::Display;
 use std::thread;
 use std::time::Duration;
-use utils::asciimap::AsciiMap;
+use utils::{asciimap::AsciiMap, solution::Solution};
 
 #[derive(Copy, Clone)]
 enum Direction {
@@ -104,21 +105,9 @@ fn throw_ray(
     }
 }
 
-fn main() -> std::io::Result<()> {
-    let input = "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...";
-    let input = std::fs::read_to_string("input")?;
-
-    let mut map = AsciiMap::from_multi_lines(input);
-    println!("{} {}", map.width(), map.height());
+/// walk guard through the map until it leaves it, return (map, marks)
+fn patrol(map: &AsciiMap) -> (AsciiMap, AsciiMap) {
+    let mut map = map.clone();
     let mut marks = map.clone();
 
     let mut guard = map.ifind('^').unwrap();
@@ -131,13 +120,37 @@ fn main() -> std::io::Result<()> {
         //thread::sleep(Duration::from_secs(1));
     }
     //map.print();
+    //marks.print();
 
-    let count: usize = ['>', '<', '^', 'v'].iter().map(|c| map.count(*c)).sum();
-    println!("P1 = {}", count);
-    let count: usize = marks.count('O');
-    println!("P2 = {}", count);
+    (map, marks)
+}
 
-    //marks.print();
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = AsciiMap;
+
+    fn parse(input: &str) -> Self::Input {
+        AsciiMap::from_multi_lines(input)
+    }
+
+    fn part1(map: &Self::Input) -> impl Display {
+        let (map, _) = patrol(map);
+        ['>', '<', '^', 'v']
+            .iter()
+            .map(|c| map.count(*c))
+            .sum::<usize>()
+    }
+
+    fn part2(map: &Self::Input) -> impl Display {
+        let (_, marks) = patrol(map);
+        marks.count('O')
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
 
-    Ok(())
+    utils::example_tests!(Puzzle; example);
 }
@@ -0,0 +1,12 @@
+//! Day 7: not solved yet
+use utils::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = ();
+
+    const SOLVED: [bool; 2] = [false, false];
+
+    fn parse(_input: &str) -> Self::Input {}
+}
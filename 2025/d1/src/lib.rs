@@ -0,0 +1,83 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use utils::solution::Solution;
+
+/// wrap input integer to 0 - 100
+fn dial_wrap(mut x: i32, r: i32, xings: &mut usize) -> i32 {
+    for _ in 0..(r.abs()) {
+        if r < 0 {
+            x -= 1;
+        } else if r > 0 {
+            x += 1;
+        }
+        if x < 0 {
+            x = x + 100;
+        } else if x >= 100 {
+            x = x - 100;
+        }
+        if x % 100 == 0 {
+            *xings += 1;
+        }
+    }
+    return x;
+}
+
+fn puzzle(rotations: &[i32]) -> (usize, usize) {
+    // simulate dial rotation
+    let mut zeros = 0usize;
+    let mut xings = 0usize;
+    // dial start at 50
+    let mut dial = 50;
+    for &r in rotations {
+        dial = dial_wrap(dial, r, &mut xings);
+        if dial == 0 {
+            zeros += 1;
+        }
+    }
+
+    (zeros, xings)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// rotations, left rotation is -, right rotation is +
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .split('\n')
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                // trim and split line
+                let (lr, value) = s.trim().split_at(1);
+                // convert value to integer
+                let value = i32::from_str(value).unwrap();
+                // apply sign
+                match lr {
+                    "L" => -value,
+                    "R" => value,
+                    _ => panic!("Unknown entry: {s:?}"),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(rotations: &Self::Input) -> impl Display {
+        puzzle(rotations).0
+    }
+
+    fn part2(rotations: &Self::Input) -> impl Display {
+        puzzle(rotations).1
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+
+    utils::answer_tests!(Puzzle; 2025, 1);
+}
@@ -1,24 +1,10 @@
-use std::io::Read;
-
-fn main() -> Result<(), std::io::Error> {
-    let mut input = "".into();
-    std::fs::File::open("input")?.read_to_string(&mut input)?;
-    let ans1 = puzzle(&input, validate1);
-    dbg!(ans1);
-    let ans2 = puzzle(&input, validate2);
-    dbg!(ans2);
-    Ok(())
-}
-
-fn puzzle(input: &str, validate: impl Fn(&str) -> bool) -> usize {
-    input
-        .trim()
-        .split(',')
-        .filter_map(|range| {
-            let (a, b) = range.split_once('-').unwrap();
-            let a: usize = a.parse().unwrap();
-            let b: usize = b.parse().unwrap();
+use std::fmt::Display;
+use utils::solution::Solution;
 
+fn puzzle(ranges: &[(usize, usize)], validate: impl Fn(&str) -> bool) -> usize {
+    ranges
+        .iter()
+        .filter_map(|&(a, b)| {
             let mut sum: usize = 0;
 
             // check all IDs in range
@@ -65,22 +51,38 @@ fn validate2(s: &str) -> bool {
     true
 }
 
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// inclusive ID ranges
+    type Input = Vec<(usize, usize)>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .split(',')
+            .map(|range| {
+                let (a, b) = range.split_once('-').unwrap();
+                (a.parse().unwrap(), b.parse().unwrap())
+            })
+            .collect()
+    }
+
+    fn part1(ranges: &Self::Input) -> impl Display {
+        puzzle(ranges, validate1)
+    }
+
+    fn part2(ranges: &Self::Input) -> impl Display {
+        puzzle(ranges, validate2)
+    }
+}
+
 #[cfg(test)]
 mod test {
 
     use super::*;
 
-    #[test]
-    fn example1() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        assert_eq!(puzzle(input, validate1), 1227775554);
-    }
-
-    #[test]
-    fn example2() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        assert_eq!(puzzle(input, validate2), 4174379265);
-    }
+    utils::example_tests!(Puzzle; example);
 
     #[test]
     fn test_validate1_true() {
@@ -117,13 +119,5 @@ mod test {
         assert_eq!(validate2("111111115"), true);
     }
 
-    #[test]
-    fn good_part1() {
-        let mut input = "".into();
-        std::fs::File::open("input")
-            .unwrap()
-            .read_to_string(&mut input)
-            .unwrap();
-        assert_eq!(puzzle(&input, validate1), 34826702005);
-    }
+    utils::answer_tests!(Puzzle; 2025, 2);
 }
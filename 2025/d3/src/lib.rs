@@ -0,0 +1,76 @@
+use std::fmt::Display;
+use utils::solution::Solution;
+
+fn puzzle(banks: &[Vec<u64>], max_joltage: impl Fn(&[u64]) -> u64) -> u64 {
+    banks.iter().map(|bank| max_joltage(&bank) as u64).sum()
+}
+fn max_joltage(bank: &[u64], n: usize) -> u64 {
+    let mut start_index = 0usize;
+    let mut acc = 0;
+    // select which batteries to turn on
+    for k in 0..n {
+        // starting from start index going left to right select the highest joltage
+        let (i, max) = bank[start_index..(bank.len() - n + k + 1)]
+            .iter()
+            .enumerate()
+            // in case of equality max_by_key return the last element,
+            // .rev() allow us to select the first element
+            .rev()
+            .max_by_key(|(_, jolt)| **jolt)
+            .unwrap();
+        // update start index
+        start_index += i + 1;
+        // accumulate joltage
+        acc = 10 * acc + max;
+    }
+    acc
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// batteries joltage of each bank
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            // parse each bank of batteries
+            .lines()
+            .map(|line| {
+                // parse each battery
+                line.chars()
+                    .map(|joltage| joltage.to_digit(10).unwrap() as u64)
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(banks: &Self::Input) -> impl Display {
+        puzzle(banks, |bank| max_joltage(bank, 2))
+    }
+
+    fn part2(banks: &Self::Input) -> impl Display {
+        puzzle(banks, |bank| max_joltage(bank, 12))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+
+    #[test]
+    fn fixing_max_joltage() {
+        let bank: Vec<u64> = "818181911112111"
+            .chars()
+            .map(|joltage| joltage.to_digit(10).unwrap() as u64)
+            .collect();
+
+        assert_eq!(max_joltage(&bank, 12), 888911112111);
+    }
+
+    utils::answer_tests!(Puzzle; 2025, 3);
+}
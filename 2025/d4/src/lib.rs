@@ -1,15 +1,5 @@
-use std::io::Read;
-
-use utils::asciimap::AsciiMap;
-
-fn main() -> Result<(), std::io::Error> {
-    let mut input = "".into();
-    std::fs::File::open("input")?.read_to_string(&mut input)?;
-
-    dbg!(puzzle1(&input));
-    dbg!(puzzle2(&input));
-    Ok(())
-}
+use std::fmt::Display;
+use utils::{asciimap::AsciiMap, solution::Solution};
 
 /// check if paper roll is accessible
 fn can_access(map: &AsciiMap, xy: (usize, usize)) -> bool {
@@ -40,17 +30,27 @@ fn can_access(map: &AsciiMap, xy: (usize, usize)) -> bool {
     rolls < 4
 }
 
-fn puzzle1(input: &str) -> u64 {
-    // convert input to 2D map
-    let map = AsciiMap::from_multi_lines(input);
+pub struct Puzzle;
 
-    accessible(&map).len() as u64
-}
+impl Solution for Puzzle {
+    type Input = AsciiMap;
+
+    fn parse(input: &str) -> Self::Input {
+        // convert input to 2D map
+        AsciiMap::from_multi_lines(input)
+    }
 
-fn puzzle2(input: &str) -> u64 {
-    // convert input to 2D map
-    let mut map = AsciiMap::from_multi_lines(input);
+    fn part1(map: &Self::Input) -> impl Display {
+        accessible(map).len() as u64
+    }
 
+    fn part2(map: &Self::Input) -> impl Display {
+        remove_accessible(map.clone())
+    }
+}
+
+/// remove accessible rolls until none is left, return removed count
+fn remove_accessible(mut map: AsciiMap) -> u64 {
     // count removed rolls
     let mut removed = 0;
 
@@ -95,43 +95,7 @@ mod test {
 
     use super::*;
 
-    #[test]
-    fn example1() {
-        let input = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
-        assert_eq!(puzzle1(input), 13);
-    }
+    utils::example_tests!(Puzzle; example);
 
-    #[test]
-    fn example2() {
-        let input = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
-        assert_eq!(puzzle2(input), 43);
-    }
-
-    #[test]
-    fn good_part1() {
-        let mut input = "".into();
-        std::fs::File::open("input")
-            .unwrap()
-            .read_to_string(&mut input)
-            .unwrap();
-        assert_eq!(puzzle1(&input), 1372);
-    }
+    utils::answer_tests!(Puzzle; 2025, 4);
 }
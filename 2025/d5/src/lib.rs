@@ -0,0 +1,59 @@
+use std::fmt::Display;
+use utils::intervals::IntervalSet;
+use utils::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// (fresh IDs database, ingredients)
+    type Input = (IntervalSet<usize>, Vec<usize>);
+
+    fn parse(input: &str) -> Self::Input {
+        // split both parts of input
+        let (db, items) = input.trim().split_once("\n\n").unwrap();
+
+        // parse IDs database, overlapping ranges are merged
+        let db: IntervalSet<usize> = db
+            .trim()
+            .split('\n')
+            .map(|s| {
+                let (a, b) = s.split_once('-').unwrap();
+                let a: usize = a.parse().unwrap();
+                let b: usize = b.parse().unwrap();
+                a..=b
+            })
+            .collect();
+
+        // parse ingredients
+        let items: Vec<usize> = items
+            .trim()
+            .split('\n')
+            .map(|s| {
+                let id: usize = s.parse().unwrap();
+                id
+            })
+            .collect();
+
+        (db, items)
+    }
+
+    fn part1((db, items): &Self::Input) -> impl Display {
+        // ingredient is fresh if its ID is in ANY range
+        items.iter().filter(|&&item| db.contains(item)).count()
+    }
+
+    fn part2((db, _items): &Self::Input) -> impl Display {
+        // count IDs considered fresh by any range of the database
+        db.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+
+    utils::answer_tests!(Puzzle; 2025, 5);
+}
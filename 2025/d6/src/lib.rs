@@ -0,0 +1,86 @@
+use std::fmt::Display;
+use utils::columns::{ColumnGroup, column_groups_with_footer};
+use utils::solution::Solution;
+
+fn problems_p1(groups: &[ColumnGroup]) -> Vec<(Vec<i64>, char)> {
+    // each problem is a group of columns, numbers are written on rows
+    groups
+        .iter()
+        .map(|group| (group.numbers_by_row(), operator(group)))
+        .collect()
+}
+
+fn problems_p2(groups: &[ColumnGroup]) -> Vec<(Vec<i64>, char)> {
+    //123 328  51 64
+    // 45 64  387 23
+    //  6 98  215 314
+    //*   +   *   +
+    groups
+        .iter()
+        .map(|group| (group.numbers_by_column(), operator(group)))
+        .collect()
+}
+
+/// return operator found in footer of a problem
+fn operator(group: &ColumnGroup) -> char {
+    let op = group.footer().and_then(|s| s.chars().next()).unwrap();
+    assert!(matches!(op, '+' | '*'), "op={op:?}");
+    op
+}
+
+fn do_op(op: char, x: i64, acc: Option<i64>) -> Option<i64> {
+    match op {
+        '+' => {
+            let acc = acc.unwrap_or(0);
+            Some(acc + x)
+        }
+        '*' => {
+            let acc = acc.unwrap_or(1);
+            Some(acc * x)
+        }
+        _ => panic!("unhandled op"),
+    }
+}
+
+fn do_ops(ops: &[(Vec<i64>, char)]) -> i64 {
+    let mut sum = 0;
+    // perform operations
+    for (nums, op) in ops {
+        let mut acc = None;
+        for x in nums {
+            acc = do_op(*op, *x, acc);
+        }
+        sum += acc.unwrap_or(0);
+    }
+
+    sum
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// problems, as groups of columns with operator in footer
+    type Input = Vec<ColumnGroup>;
+
+    fn parse(input: &str) -> Self::Input {
+        column_groups_with_footer(input)
+    }
+
+    fn part1(groups: &Self::Input) -> impl Display {
+        do_ops(&problems_p1(groups)) as u64
+    }
+
+    fn part2(groups: &Self::Input) -> impl Display {
+        do_ops(&problems_p2(groups)) as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+
+    utils::answer_tests!(Puzzle; 2025, 6);
+}
@@ -1,13 +1,6 @@
-use std::{collections::HashMap, io::Read};
-use utils::asciimap::AsciiMap;
-
-fn main() -> Result<(), std::io::Error> {
-    let mut input = "".into();
-    std::fs::File::open("input")?.read_to_string(&mut input)?;
-    dbg!(puzzle1(&input));
-    dbg!(puzzle2(&input));
-    Ok(())
-}
+use std::collections::HashMap;
+use std::fmt::Display;
+use utils::{asciimap::AsciiMap, solution::Solution};
 
 // Quantum Tachyon Manifold (tm)
 struct QTM {
@@ -19,8 +12,7 @@ struct QTM {
 }
 
 impl QTM {
-    fn new(input: &str) -> Self {
-        let map = AsciiMap::from_multi_lines(input);
+    fn new(map: AsciiMap) -> Self {
         Self {
             map,
             nsplits: 0,
@@ -91,93 +83,30 @@ impl QTM {
     }
 }
 
-#[allow(unused)]
-fn puzzle1(input: &str) -> u64 {
-    QTM::new(input).solve1() as u64
-}
-
-#[allow(unused)]
-fn puzzle2(input: &str) -> u64 {
-    QTM::new(input).solve2() as u64
-}
-
-#[cfg(test)]
-mod test {
+pub struct Puzzle;
 
-    use super::*;
+impl Solution for Puzzle {
+    type Input = AsciiMap;
 
-    #[test]
-    fn example1() {
-        let input = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............";
-        assert_eq!(puzzle1(input), 21);
+    fn parse(input: &str) -> Self::Input {
+        AsciiMap::from_multi_lines(input)
     }
 
-    #[test]
-    fn example2() {
-        let input = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............";
-        assert_eq!(puzzle2(input), 40);
+    fn part1(map: &Self::Input) -> impl Display {
+        QTM::new(map.clone()).solve1() as u64
     }
 
-    #[test]
-    fn example2_smaller() {
-        let input = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............";
-        assert_eq!(puzzle2(input), 8);
+    fn part2(map: &Self::Input) -> impl Display {
+        QTM::new(map.clone()).solve2() as u64
     }
+}
 
-    #[test]
-    fn good_part1() {
-        let mut input = "".into();
-        std::fs::File::open("input")
-            .unwrap()
-            .read_to_string(&mut input)
-            .unwrap();
-        assert_eq!(puzzle1(&input), 1524);
-    }
+#[cfg(test)]
+mod test {
 
-    #[test]
-    fn good_part2() {
-        let mut input = "".into();
-        std::fs::File::open("input")
-            .unwrap()
-            .read_to_string(&mut input)
-            .unwrap();
-        assert_eq!(puzzle2(&input), 32982105837605);
-    }
+    use super::*;
+
+    utils::example_tests!(Puzzle; example1, example2);
+
+    utils::answer_tests!(Puzzle; 2025, 7);
 }
@@ -0,0 +1,123 @@
+use std::{fmt::Display, str::FromStr};
+use utils::kdtree::{KdTree, Point};
+use utils::mst::{self, Kruskal};
+use utils::solution::Solution;
+
+#[derive(Debug, Copy, Clone)]
+pub struct JunctionBox {
+    x: isize,
+    y: isize,
+    z: isize,
+}
+
+impl JunctionBox {
+    fn new(x: isize, y: isize, z: isize) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl FromStr for JunctionBox {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, &'static str> {
+        let mut ns = s.split(',');
+
+        let x = ns
+            .next()
+            .ok_or("not enough coordinates")?
+            .parse::<isize>()
+            .map_err(|_| "unable to parse integer")?;
+
+        let y = ns
+            .next()
+            .ok_or("not enough coordinates")?
+            .parse::<isize>()
+            .map_err(|_| "unable to parse integer")?;
+
+        let z = ns
+            .next()
+            .ok_or("not enough coordinates")?
+            .parse::<isize>()
+            .map_err(|_| "unable to parse integer")?;
+
+        Ok(JunctionBox::new(x, y, z))
+    }
+}
+
+/// Giant Playground
+struct GP {
+    boxes: Vec<JunctionBox>,
+}
+
+impl GP {
+    fn new(boxes: Vec<JunctionBox>) -> Self {
+        Self { boxes }
+    }
+
+    fn points(&self) -> Vec<Point<3>> {
+        self.boxes
+            .iter()
+            .map(|b| [b.x as i64, b.y as i64, b.z as i64])
+            .collect()
+    }
+
+    fn solve1(&self, nconnections: usize) -> u64 {
+        // -- connect closest boxes --
+        // each boxes is EXACTLY part of ONE circuit
+        let tree = KdTree::new(self.points());
+        let mut circuits = Kruskal::new(self.boxes.len(), tree.closest_pairs(nconnections));
+        while circuits.consider().is_some() {}
+
+        // multiply together the size of the 3 largest circuits
+        circuits.forest().largest(3).iter().product::<usize>() as u64
+    }
+
+    fn solve2(&self) -> u64 {
+        // connect boxes ordered by distance between them, until all boxes
+        // are in a single circuit
+        let tree = mst::point_spanning_tree(&self.points());
+        let last = tree.last().expect("there are at least two boxes");
+
+        // get two last connected boxes
+        let boxa = self.boxes[last.a];
+        let boxb = self.boxes[last.b];
+        // return puzzle solution
+        (boxa.x * boxb.x) as u64
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<JunctionBox>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .split('\n')
+            .map(|line| JunctionBox::from_str(line).unwrap())
+            .collect()
+    }
+
+    fn part1(boxes: &Self::Input) -> impl Display {
+        GP::new(boxes.clone()).solve1(1000)
+    }
+
+    fn part2(boxes: &Self::Input) -> impl Display {
+        GP::new(boxes.clone()).solve2()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    utils::example_tests! {
+        part1 => |input: &str| GP::new(Puzzle::parse(input)).solve1(10),
+        part2 => |input: &str| Puzzle::part2(&Puzzle::parse(input)).to_string();
+        example
+    }
+
+    utils::answer_tests!(Puzzle; 2025, 8);
+}
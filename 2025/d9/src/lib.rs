@@ -1,12 +1,6 @@
-use std::{collections::BinaryHeap, io::Read};
-
-fn main() -> Result<(), std::io::Error> {
-    let mut input = "".into();
-    std::fs::File::open("input")?.read_to_string(&mut input)?;
-    dbg!(TileFloor::from_input(&input).largest_rectangle());
-    dbg!(TileFloor::from_input(&input).largest_rectangle_non_empty());
-    Ok(())
-}
+use std::collections::BinaryHeap;
+use std::fmt::Display;
+use utils::solution::Solution;
 
 /// Represent an horizontal or a vertical segment used in ray casting algorithm
 #[derive(Debug)]
@@ -65,7 +59,7 @@ impl Ord for Rectangle {
     }
 }
 
-struct TileFloor {
+pub struct TileFloor {
     /// red tile list
     reds: Vec<(isize, isize)>,
     /// vertical segments in area boundary, ordered by x position
@@ -286,7 +280,7 @@ impl TileFloor {
     }
 
     /// Return largest rectangle area without empty floor
-    fn largest_rectangle_non_empty(&mut self) -> isize {
+    fn largest_rectangle_non_empty(&self) -> isize {
         let n = self.reds.len();
 
         // ---
@@ -330,11 +324,30 @@ impl TileFloor {
     }
 }
 
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = TileFloor;
+
+    fn parse(input: &str) -> Self::Input {
+        TileFloor::from_input(input)
+    }
+
+    fn part1(floor: &Self::Input) -> impl Display {
+        floor.largest_rectangle()
+    }
+
+    fn part2(floor: &Self::Input) -> impl Display {
+        floor.largest_rectangle_non_empty()
+    }
+}
+
 #[cfg(test)]
 mod test {
 
     use super::*;
     use utils::asciimap::AsciiMap;
+    use utils::examples::Example;
 
     fn ascii_map_to_plan(map: &str) -> String {
         // asciimap use screen space coordinates but not TileFloor
@@ -353,38 +366,18 @@ mod test {
         plan
     }
 
-    #[test]
-    fn example1() {
-        let input = "
-7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3"
-        .trim();
-        let tf = TileFloor::from_input(input);
-        assert_eq!(tf.surface_of(4, 6), 24);
-        assert_eq!(tf.surface_of(6, 4), 24);
-        assert_eq!(tf.largest_rectangle(), 50);
+    utils::example_tests!(Puzzle; example);
+
+    fn example_floor() -> TileFloor {
+        let example = Example::parse(include_str!("../example"));
+        TileFloor::from_input(&example.input)
     }
 
     #[test]
-    fn example2() {
-        let input = "
-7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3"
-        .trim();
-        let mut tf = TileFloor::from_input(input);
-        assert_eq!(tf.largest_rectangle_non_empty(), 24);
+    fn surfaces() {
+        let tf = example_floor();
+        assert_eq!(tf.surface_of(4, 6), 24);
+        assert_eq!(tf.surface_of(6, 4), 24);
     }
 
     #[test]
@@ -493,16 +486,7 @@ mod test {
     }
 
     #[test]
-    fn contained_example2() {
-        let input = "
-7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3";
+    fn contained_example() {
         //  0123456789012
         //7          3-2
         //6          | |
@@ -513,7 +497,7 @@ mod test {
         //1        0---1
         //0
         //  0123456789012
-        let tf = TileFloor::from_input(input);
+        let tf = example_floor();
         assert_eq!(tf.is_contained(1, 7), true);
         assert_eq!(tf.is_contained(3, 4), true);
         assert_eq!(tf.is_contained(4, 6), true);
@@ -524,25 +508,5 @@ mod test {
         assert_eq!(tf.surface_of(3, 5), 24);
     }
 
-    #[test]
-    fn good_part1() {
-        let mut input = "".into();
-        std::fs::File::open("input")
-            .unwrap()
-            .read_to_string(&mut input)
-            .unwrap();
-        let tf = TileFloor::from_input(&input);
-        assert_eq!(tf.largest_rectangle(), 4763932976);
-    }
-
-    #[test]
-    fn good_part2() {
-        let mut input = "".into();
-        std::fs::File::open("input")
-            .unwrap()
-            .read_to_string(&mut input)
-            .unwrap();
-        let mut tf = TileFloor::from_input(&input);
-        assert_eq!(tf.largest_rectangle_non_empty(), 1501292304);
-    }
+    utils::answer_tests!(Puzzle; 2025, 9);
 }
@@ -0,0 +1,381 @@
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Mutex};
+use std::time::{Duration, Instant};
+
+use utils::inputs::{InputError, Loader, INPUT_VAR};
+use utils::trace;
+
+use crate::days::{self, Day};
+use crate::report::{Format, Record, Status};
+use crate::usage;
+
+/// Seconds a day may run before being killed when not given
+const DEFAULT_TIMEOUT: u64 = 60;
+
+/// Parsed `all` arguments
+pub struct AllArgs {
+    /// only run this year
+    year: Option<u32>,
+    /// worker count, defaults to available parallelism
+    jobs: usize,
+    timeout: Duration,
+    /// report format, summary text when not given
+    format: Option<Format>,
+    /// write report to this file instead of stdout
+    output: Option<PathBuf>,
+}
+
+impl AllArgs {
+    pub fn parse(
+        mut args: impl Iterator<Item = String>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut all = Self {
+            year: None,
+            jobs: std::thread::available_parallelism().map_or(1, |n| n.get()),
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT),
+            format: None,
+            output: None,
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| usage(format!("missing value for {arg}")))
+            };
+            let mut number = |what: &str| -> Result<u64, Box<dyn std::error::Error>> {
+                let value = value()?;
+                match value.parse() {
+                    Ok(0) | Err(_) => Err(usage(format!("invalid {what} '{value}'"))),
+                    Ok(n) => Ok(n),
+                }
+            };
+            match arg.as_str() {
+                "--jobs" | "-j" => all.jobs = number("jobs")? as usize,
+                "--timeout" | "-t" => all.timeout = Duration::from_secs(number("timeout")?),
+                "--format" | "-f" => {
+                    let format = value()?;
+                    all.format = Some(
+                        Format::parse(&format)
+                            .ok_or_else(|| usage(format!("invalid format '{format}'")))?,
+                    );
+                }
+                "--output" | "-o" => all.output = Some(PathBuf::from(value()?)),
+                _ if all.year.is_none() => {
+                    all.year = Some(
+                        arg.parse()
+                            .map_err(|_| usage(format!("unknown argument '{arg}'")))?,
+                    )
+                }
+                _ => return Err(usage(format!("unexpected argument '{arg}'"))),
+            }
+        }
+        Ok(all)
+    }
+}
+
+/// How running a day ended
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    /// records of each part
+    Solved(Vec<Record>),
+    /// day panicked or failed, with its last error line
+    Failed(String),
+    TimedOut,
+    /// day did not run, for given reason
+    Skipped(String),
+}
+
+/// Result of running a day in its own process
+pub struct DayRun {
+    pub year: u32,
+    pub day: u32,
+    pub outcome: Outcome,
+    pub duration: Duration,
+}
+
+impl DayRun {
+    /// Report records of each part, parts which did not run share the day error
+    pub fn records(&self, timeout: Duration) -> Vec<Record> {
+        let (status, error) = match &self.outcome {
+            Outcome::Solved(records) => return records.clone(),
+            Outcome::Failed(error) => {
+                let error = error.strip_prefix("error: ").unwrap_or(error);
+                (Status::Error, error.to_string())
+            }
+            Outcome::TimedOut => (Status::Timeout, format!("timed out after {timeout:?}")),
+            Outcome::Skipped(reason) => (Status::Skipped, reason.clone()),
+        };
+        let day = days::find(self.year, self.day);
+        [1, 2]
+            .into_iter()
+            .map(|part| {
+                if day.is_some_and(|day| day.is_solved(part)) {
+                    Record::failed((self.year, self.day, part), status, &error)
+                } else {
+                    Record::solved((self.year, self.day, part), None, None, Duration::ZERO)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Error reported by a failed run, else its last meaningful line (e.g. the panic message)
+fn error_line(stderr: &str) -> String {
+    let lines = stderr.lines().map(str::trim);
+    lines
+        .clone()
+        .find(|line| line.starts_with("error:"))
+        .or_else(|| {
+            // panic message follows the `thread '...' panicked at <location>:` line
+            lines
+                .clone()
+                .skip_while(|line| !line.contains(" panicked at "))
+                .nth(1)
+        })
+        .or_else(|| {
+            lines.clone().rfind(|line| {
+                !line.is_empty()
+                    && !line.starts_with("note:")
+                    && !line.starts_with("no puzzle input")
+            })
+        })
+        .unwrap_or("no error output")
+        .to_string()
+}
+
+/// Run a day in a child process, killing it once timeout is reached
+fn run_day(
+    day: &Day,
+    timeout: Duration,
+) -> Result<DayRun, Box<dyn std::error::Error + Send + Sync>> {
+    // days without any input can not run, which is no failure
+    let solved = [1, 2].into_iter().any(|part| day.is_solved(part));
+    if let Err(InputError::NotFound { .. }) = Loader::new(day.year, day.day).locate() {
+        if solved {
+            return Ok(DayRun {
+                year: day.year,
+                day: day.day,
+                outcome: Outcome::Skipped("no input found".to_string()),
+                duration: Duration::ZERO,
+            });
+        }
+    }
+
+    let start = Instant::now();
+    let mut child = Command::new(std::env::current_exe()?)
+        .args(["run", &day.year.to_string(), &day.day.to_string(), "--json"])
+        .env(trace::LOG_VAR, trace::filter().to_string())
+        // an input given for one run is not the input of every day
+        .env_remove(INPUT_VAR)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // drain pipes while waiting, a full pipe would block the child, each
+    // pipe is reported once closed which happens when the child exits
+    let (closed, closing) = mpsc::channel();
+    let drain = |pipe: Option<Box<dyn Read + Send>>| {
+        let closed = closed.clone();
+        std::thread::spawn(move || {
+            let mut out = String::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_string(&mut out);
+            }
+            let _ = closed.send(());
+            out
+        })
+    };
+    let stdout = drain(
+        child
+            .stdout
+            .take()
+            .map(|p| Box::new(p) as Box<dyn Read + Send>),
+    );
+    let stderr = drain(
+        child
+            .stderr
+            .take()
+            .map(|p| Box::new(p) as Box<dyn Read + Send>),
+    );
+
+    let exited = (0..2).all(|_| {
+        closing
+            .recv_timeout(timeout.saturating_sub(start.elapsed()))
+            .is_ok()
+    });
+    let status = if exited {
+        Some(child.wait()?)
+    } else {
+        child.kill()?;
+        child.wait()?;
+        None
+    };
+    let duration = start.elapsed();
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    let outcome = match status {
+        None => Outcome::TimedOut,
+        Some(status) if status.success() => match serde_json::from_str(&stdout) {
+            Ok(records) => Outcome::Solved(records),
+            Err(e) => Outcome::Failed(format!("invalid run output: {e}")),
+        },
+        Some(_) => Outcome::Failed(error_line(&stderr)),
+    };
+    Ok(DayRun {
+        year: day.year,
+        day: day.day,
+        outcome,
+        duration,
+    })
+}
+
+/// Run days on a pool of workers, results are given in days order
+pub fn run_days(
+    days: &[&'static Day],
+    jobs: usize,
+    timeout: Duration,
+) -> Result<Vec<DayRun>, Box<dyn std::error::Error>> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![]);
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            scope.spawn(|| loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(day) = days.get(idx) else {
+                    break;
+                };
+                let run = run_day(day, timeout).map_err(|e| e.to_string());
+                results.lock().unwrap().push((idx, run));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(idx, _)| *idx);
+    results
+        .into_iter()
+        .map(|(_, run)| run.map_err(|e| e.into()))
+        .collect()
+}
+
+pub fn all(args: AllArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let selected: Vec<&'static Day> = days::DAYS
+        .iter()
+        .filter(|day| args.year.is_none_or(|year| year == day.year))
+        .collect();
+    let start = Instant::now();
+    let runs = run_days(&selected, args.jobs, args.timeout)?;
+    let elapsed = start.elapsed();
+
+    if let Some(format) = args.format {
+        let records: Vec<Record> = runs
+            .iter()
+            .flat_map(|run| run.records(args.timeout))
+            .collect();
+        let report = format.render(&records);
+        match &args.output {
+            Some(path) => std::fs::write(path, report)
+                .map_err(|e| format!("unable to write {}: {e}", path.display()))?,
+            None => print!("{report}"),
+        }
+        return Ok(());
+    }
+
+    let (mut solved, mut failed, mut timed_out, mut skipped) = (0, 0, 0, 0);
+    for run in &runs {
+        let summary = match &run.outcome {
+            Outcome::Solved(records) => {
+                solved += 1;
+                let answers: Vec<String> = records
+                    .iter()
+                    .map(|record| {
+                        let answer = record.answer.as_deref().unwrap_or("not solved");
+                        format!("part {}: {answer}", record.part)
+                    })
+                    .collect();
+                format!("{:<9}  {}", "ok", answers.join("  "))
+            }
+            Outcome::Failed(error) => {
+                failed += 1;
+                format!("{:<9}  {error}", "FAILED")
+            }
+            Outcome::TimedOut => {
+                timed_out += 1;
+                "TIMEOUT".to_string()
+            }
+            Outcome::Skipped(reason) => {
+                skipped += 1;
+                format!("{:<9}  {reason}", "skipped")
+            }
+        };
+        println!(
+            "{} day {:>2}  {:>10}  {summary}",
+            run.year,
+            run.day,
+            format!("{:.2?}", run.duration)
+        );
+    }
+    println!(
+        "{solved} ok, {failed} failed, {timed_out} timed out, {skipped} skipped in {elapsed:.2?}"
+    );
+
+    if failed + timed_out > 0 {
+        Err(format!("{} days did not complete", failed + timed_out).into())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_records() {
+        let run = DayRun {
+            year: 2023,
+            day: 7,
+            outcome: Outcome::TimedOut,
+            duration: Duration::from_secs(1),
+        };
+        let records = run.records(Duration::from_secs(1));
+        assert_eq!(records[0].status, Status::Unsolved);
+        assert_eq!(records[1].status, Status::Timeout);
+        assert_eq!(records[1].error.as_deref(), Some("timed out after 1s"));
+
+        let run = DayRun {
+            outcome: Outcome::Skipped("no input found".to_string()),
+            ..run
+        };
+        let records = run.records(Duration::from_secs(1));
+        assert_eq!(records[0].status, Status::Unsolved);
+        assert_eq!(records[1].status, Status::Skipped);
+        assert_eq!(records[1].error.as_deref(), Some("no input found"));
+    }
+
+    #[test]
+    fn errors() {
+        let stderr = "no puzzle input, using 2023/d17/example1 (example)
+
+thread 'main' (1234) panicked at 2023/d17/src/lib.rs:220:9:
+not yet implemented
+stack backtrace:
+   0: __rustc::rust_begin_unwind
+";
+        assert_eq!(error_line(stderr), "not yet implemented");
+        let stderr = "error: no input found for 2025 day 1, searched:
+  2025/d1/input
+set AOC_INPUT_DIR to a directory of inputs or give an input path (- for stdin)
+";
+        assert_eq!(
+            error_line(stderr),
+            "error: no input found for 2025 day 1, searched:"
+        );
+        assert_eq!(error_line("bad input\nnote: ignored\n"), "bad input");
+        assert_eq!(error_line(""), "no error output");
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::days::{self, Day};
+use crate::usage;
+
+/// Runs per day when not given
+const DEFAULT_RUNS: usize = 10;
+
+/// Median slowdown over baseline, in percent, flagged as a regression when not given
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Parsed `bench` arguments
+pub struct BenchArgs {
+    /// only bench this year
+    year: Option<u32>,
+    /// only bench this day of year
+    day: Option<u32>,
+    runs: usize,
+    baseline: Option<PathBuf>,
+    /// store results in baseline
+    save: bool,
+    threshold: f64,
+}
+
+impl BenchArgs {
+    pub fn parse(
+        mut args: impl Iterator<Item = String>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut bench = Self {
+            year: None,
+            day: None,
+            runs: DEFAULT_RUNS,
+            baseline: None,
+            save: false,
+            threshold: DEFAULT_THRESHOLD,
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| usage(format!("missing value for {arg}")))
+            };
+            match arg.as_str() {
+                "--runs" | "-r" => {
+                    let runs = value()?;
+                    bench.runs = match runs.parse() {
+                        Ok(0) | Err(_) => return Err(usage(format!("invalid runs '{runs}'"))),
+                        Ok(runs) => runs,
+                    };
+                }
+                "--baseline" | "-b" => bench.baseline = Some(PathBuf::from(value()?)),
+                "--save" => bench.save = true,
+                "--threshold" | "-t" => {
+                    let threshold = value()?;
+                    bench.threshold = threshold
+                        .parse()
+                        .map_err(|_| usage(format!("invalid threshold '{threshold}'")))?;
+                }
+                _ => {
+                    let number = arg
+                        .parse()
+                        .map_err(|_| usage(format!("unknown argument '{arg}'")))?;
+                    if bench.year.is_none() {
+                        bench.year = Some(number);
+                    } else if bench.day.is_none() {
+                        bench.day = Some(number);
+                    } else {
+                        return Err(usage(format!("unexpected argument '{arg}'")));
+                    }
+                }
+            }
+        }
+        Ok(bench)
+    }
+
+    fn selects(&self, day: &Day) -> bool {
+        self.year.is_none_or(|year| year == day.year) && self.day.is_none_or(|d| d == day.day)
+    }
+}
+
+/// Timing of a step over repeated runs
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    fn new(mut durations: Vec<Duration>) -> Self {
+        assert!(!durations.is_empty());
+        durations.sort();
+        let ns = |d: Duration| d.as_nanos() as u64;
+        let n = durations.len();
+        let median = if n.is_multiple_of(2) {
+            (durations[n / 2 - 1] + durations[n / 2]) / 2
+        } else {
+            durations[n / 2]
+        };
+        Self {
+            runs: n,
+            min_ns: ns(durations[0]),
+            median_ns: ns(median),
+            max_ns: ns(durations[n - 1]),
+        }
+    }
+
+    /// slowdown of median over baseline median, in percent
+    fn slowdown(&self, baseline: &Stats) -> f64 {
+        (self.median_ns as f64 / baseline.median_ns.max(1) as f64 - 1.0) * 100.0
+    }
+}
+
+/// Bench results keyed by `<year>/d<day>/<step>`, step being `parse`, `part1` or `part2`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Baseline(BTreeMap<String, Stats>);
+
+impl Baseline {
+    /// Load baseline file, a missing file is an empty baseline
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text)
+                .map_err(|e| format!("invalid baseline {}: {e}", path.display()).into()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("unable to read {}: {e}", path.display()).into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json + "\n")
+            .map_err(|e| format!("unable to write {}: {e}", path.display()).into())
+    }
+}
+
+fn key(year: u32, day: u32, step: &str) -> String {
+    format!("{year}/d{day}/{step}")
+}
+
+/// Baseline given with `--baseline`, else `bench.json` at the root of the repository
+fn default_baseline() -> PathBuf {
+    // aoc lives at the root of the repository
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join("bench.json")
+}
+
+/// Timing of each step of a day, keyed like [`Baseline`] entries
+type DayStats = Vec<(String, Stats)>;
+
+/// Time parse and each solved part of day over repeated runs, `None` if day
+/// has no puzzle input
+fn bench_day(day: &Day, runs: usize) -> Result<Option<DayStats>, Box<dyn std::error::Error>> {
+    let parts: Vec<u32> = [1, 2]
+        .into_iter()
+        .filter(|&part| day.is_solved(part))
+        .collect();
+    if parts.is_empty() {
+        return Ok(None);
+    }
+
+    // example timings must never end up in, nor be compared with, the baseline
+    let loader = utils::inputs::Loader::new(day.year, day.day).examples(false);
+    let input = match loader.load() {
+        Ok(input) => input,
+        Err(utils::inputs::InputError::NotFound { .. }) => {
+            eprintln!("no puzzle input for {} day {}, skipped", day.year, day.day);
+            return Ok(None);
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut parse = vec![];
+    let mut solve = vec![vec![]; parts.len()];
+    for _ in 0..runs {
+        let timed = day.solve_timed(&input, &parts);
+        parse.push(timed.parse);
+        for (durations, (_, duration, _)) in solve.iter_mut().zip(timed.parts) {
+            durations.push(duration);
+        }
+    }
+
+    let mut stats = vec![(key(day.year, day.day, "parse"), Stats::new(parse))];
+    for (part, durations) in parts.iter().zip(solve) {
+        stats.push((
+            key(day.year, day.day, &format!("part{part}")),
+            Stats::new(durations),
+        ));
+    }
+    Ok(Some(stats))
+}
+
+/// format duration given in nanoseconds
+fn ns(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+pub fn bench(args: BenchArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let path = args.baseline.clone().unwrap_or_else(default_baseline);
+    let mut baseline = Baseline::load(&path)?;
+
+    println!(
+        "{:<16}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "step", "min", "median", "max", "baseline"
+    );
+    let mut regressions = 0;
+    for day in days::DAYS.iter().filter(|day| args.selects(day)) {
+        let Some(stats) = bench_day(day, args.runs)? else {
+            continue;
+        };
+        for (key, stats) in stats {
+            let compared = match baseline.0.get(&key) {
+                Some(base) => {
+                    let slowdown = stats.slowdown(base);
+                    let flag = if slowdown > args.threshold {
+                        regressions += 1;
+                        "  REGRESSION"
+                    } else {
+                        ""
+                    };
+                    format!("{:>10}  {slowdown:+.1}%{flag}", ns(base.median_ns))
+                }
+                None => format!("{:>10}", "-"),
+            };
+            println!(
+                "{key:<16}  {:>10}  {:>10}  {:>10}  {compared}",
+                ns(stats.min_ns),
+                ns(stats.median_ns),
+                ns(stats.max_ns)
+            );
+            if args.save {
+                baseline.0.insert(key, stats);
+            }
+        }
+    }
+
+    if args.save {
+        baseline.save(&path)?;
+        println!("baseline saved to {}", path.display());
+    }
+    if regressions > 0 {
+        Err(format!(
+            "{regressions} steps are more than {}% slower than baseline",
+            args.threshold
+        )
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(durations: &[u64]) -> Vec<Duration> {
+        durations
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect()
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::new(ms(&[5, 1, 3]));
+        assert_eq!(stats.runs, 3);
+        assert_eq!(stats.min_ns, 1_000_000);
+        assert_eq!(stats.median_ns, 3_000_000);
+        assert_eq!(stats.max_ns, 5_000_000);
+
+        let stats = Stats::new(ms(&[4, 1, 2, 8]));
+        assert_eq!(stats.median_ns, 3_000_000);
+    }
+
+    #[test]
+    fn slowdown() {
+        let base = Stats::new(ms(&[10]));
+        assert_eq!(Stats::new(ms(&[12])).slowdown(&base).round(), 20.0);
+        assert_eq!(Stats::new(ms(&[5])).slowdown(&base).round(), -50.0);
+    }
+
+    #[test]
+    fn baseline_roundtrip() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.json", std::process::id()));
+        assert_eq!(Baseline::load(&path).unwrap(), Baseline::default());
+
+        let mut baseline = Baseline::default();
+        baseline
+            .0
+            .insert(key(2023, 17, "part1"), Stats::new(ms(&[1, 2, 3])));
+        baseline.save(&path).unwrap();
+        assert_eq!(Baseline::load(&path).unwrap(), baseline);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
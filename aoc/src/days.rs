@@ -0,0 +1,132 @@
+use utils::solution::Timed;
+
+/// Parse input once then answer requested parts, `None` for unsolved parts
+pub type Solver = fn(&str, &[u32]) -> Timed;
+
+/// A registered day
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    solver: Solver,
+    solved: fn(u32) -> bool,
+    #[cfg(test)]
+    check_solved: fn(&str),
+}
+
+impl Day {
+    /// whether part (1 or 2) is solved
+    pub fn is_solved(&self, part: u32) -> bool {
+        (self.solved)(part)
+    }
+
+    /// answer each requested part, in request order
+    pub fn solve(&self, input: &str, parts: &[u32]) -> Vec<Option<String>> {
+        self.solve_timed(input, parts)
+            .parts
+            .into_iter()
+            .map(|(answer, _, _)| answer)
+            .collect()
+    }
+
+    /// answer each requested part, along with time spent in each step
+    pub fn solve_timed(&self, input: &str, parts: &[u32]) -> Timed {
+        (self.solver)(input, parts)
+    }
+}
+
+/// Register a day from the type implementing `utils::solution::Solution`:
+///
+/// ```ignore
+/// day!(2023, 1, aoc2023_d1::Puzzle)
+/// ```
+macro_rules! day {
+    ($year:literal, $day:literal, $solution:ty) => {
+        Day {
+            year: $year,
+            day: $day,
+            solver: utils::solution::solve_timed::<$solution>,
+            solved: utils::solution::is_solved::<$solution>,
+            #[cfg(test)]
+            check_solved: utils::solution::check_solved::<$solution>,
+        }
+    };
+}
+
+/// Every registered day, sorted by year then day
+pub static DAYS: &[Day] = &[
+    // -- 2023 --
+    day!(2023, 1, aoc2023_d1::Puzzle),
+    day!(2023, 2, aoc2023_d2::Puzzle),
+    day!(2023, 3, aoc2023_d3::Puzzle),
+    day!(2023, 4, aoc2023_d4::Puzzle),
+    day!(2023, 5, aoc2023_d5::Puzzle),
+    day!(2023, 6, aoc2023_d6::Puzzle),
+    day!(2023, 7, aoc2023_d7::Puzzle),
+    day!(2023, 8, aoc2023_d8::Puzzle),
+    day!(2023, 9, aoc2023_d9::Puzzle),
+    day!(2023, 10, aoc2023_d10::Puzzle),
+    day!(2023, 11, aoc2023_d11::Puzzle),
+    day!(2023, 13, aoc2023_d13::Puzzle),
+    day!(2023, 14, aoc2023_d14::Puzzle),
+    day!(2023, 15, aoc2023_d15::Puzzle),
+    day!(2023, 16, aoc2023_d16::Puzzle),
+    day!(2023, 17, aoc2023_d17::Puzzle),
+    // -- 2024 --
+    day!(2024, 1, aoc2024_d1::Puzzle),
+    day!(2024, 2, aoc2024_d2::Puzzle),
+    day!(2024, 3, aoc2024_d3::Puzzle),
+    day!(2024, 4, aoc2024_d4::Puzzle),
+    day!(2024, 5, aoc2024_d5::Puzzle),
+    day!(2024, 6, aoc2024_d6::Puzzle),
+    day!(2024, 7, aoc2024_d7::Puzzle),
+    // -- 2025 --
+    day!(2025, 1, aoc2025_d1::Puzzle),
+    day!(2025, 2, aoc2025_d2::Puzzle),
+    day!(2025, 3, aoc2025_d3::Puzzle),
+    day!(2025, 4, aoc2025_d4::Puzzle),
+    day!(2025, 5, aoc2025_d5::Puzzle),
+    day!(2025, 6, aoc2025_d6::Puzzle),
+    day!(2025, 7, aoc2025_d7::Puzzle),
+    day!(2025, 8, aoc2025_d8::Puzzle),
+    day!(2025, 9, aoc2025_d9::Puzzle),
+];
+
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_sorted() {
+        let keys: Vec<(u32, u32)> = DAYS.iter().map(|d| (d.year, d.day)).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn find_day() {
+        let day = find(2024, 1).unwrap();
+        assert_eq!(
+            day.solve("3   4\n4   3\n2   5\n1   3\n3   9\n3   3", &[1, 2]),
+            vec![Some("11".to_string()), Some("31".to_string())]
+        );
+        assert!(!find(2024, 7).unwrap().is_solved(1));
+        assert!(find(2023, 7).unwrap().is_solved(2));
+        assert!(find(2023, 12).is_none());
+    }
+
+    #[test]
+    fn solved_matches_parts() {
+        for day in DAYS {
+            match utils::inputs::load(day.year, day.day) {
+                Ok(input) => (day.check_solved)(&input),
+                Err(_) => eprintln!("{} day {}: skipped, no input", day.year, day.day),
+            }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use report::Record;
+use utils::answers::Answers;
+use utils::examples::Example;
+use utils::inputs::Source;
+
+mod all;
+mod bench;
+mod days;
+mod report;
+mod scaffold;
+mod stats;
+mod verify;
+
+/// counts allocations once `--alloc` enables it
+#[global_allocator]
+static ALLOC: utils::alloc::Counting = utils::alloc::Counting;
+
+const USAGE: &str = "usage: aoc [--log <filter>] <command>
+    aoc run <year> <day> [--part <1|2>] [--input <path>] [--json] [--alloc]
+    aoc all [<year>] [--jobs <n>] [--timeout <seconds>] [--format <json|junit|markdown>] [--output <path>]
+    aoc verify [<year> [<day>]] [--answers <path>]
+    aoc bench [<year> [<day>]] [--runs <n>] [--baseline <path>] [--save] [--threshold <percent>]
+    aoc new <year> <day>
+    aoc stats <year> <day> [--input <path>]
+    aoc list
+
+filter is a comma separated list of <level> or <module>=<level> (e.g. warn,aoc2023_d17=trace),
+with level one of off, error, warn, info, debug, trace, defaults to AOC_LOG
+--input defaults to AOC_INPUT, - reads stdin";
+
+#[derive(Debug)]
+struct UsageError(String);
+
+impl std::fmt::Display for UsageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{USAGE}", self.0)
+    }
+}
+
+impl std::error::Error for UsageError {}
+
+fn usage(reason: impl Into<String>) -> Box<dyn std::error::Error> {
+    Box::new(UsageError(reason.into()))
+}
+
+/// Parsed `run` arguments
+struct RunArgs {
+    year: u32,
+    day: u32,
+    /// run a single part instead of both
+    part: Option<u32>,
+    input: Option<PathBuf>,
+    /// print report records instead of answers
+    json: bool,
+    /// count allocations of each part
+    alloc: bool,
+}
+
+impl RunArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut number = |what: &str| -> Result<u32, Box<dyn std::error::Error>> {
+            let arg = args
+                .next()
+                .ok_or_else(|| usage(format!("missing {what}")))?;
+            arg.parse()
+                .map_err(|_| usage(format!("invalid {what} '{arg}'")))
+        };
+        let year = number("year")?;
+        let day = number("day")?;
+
+        let mut run = Self {
+            year,
+            day,
+            part: None,
+            input: None,
+            json: false,
+            alloc: false,
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| usage(format!("missing value for {arg}")))
+            };
+            match arg.as_str() {
+                "--part" | "-p" => {
+                    let part = value()?;
+                    match part.as_str() {
+                        "1" => run.part = Some(1),
+                        "2" => run.part = Some(2),
+                        _ => return Err(usage(format!("invalid part '{part}'"))),
+                    }
+                }
+                "--input" | "-i" => run.input = Some(PathBuf::from(value()?)),
+                "--json" => run.json = true,
+                "--alloc" => run.alloc = true,
+                _ => return Err(usage(format!("unknown argument '{arg}'"))),
+            }
+        }
+        Ok(run)
+    }
+}
+
+/// input path of a run given in the environment, when `--input` is not
+fn input_var() -> Option<PathBuf> {
+    std::env::var_os(utils::inputs::INPUT_VAR).map(PathBuf::from)
+}
+
+fn run(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let day = days::find(args.year, args.day)
+        .ok_or_else(|| format!("{} day {} is not registered", args.year, args.day))?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    if parts.iter().all(|&part| !day.is_solved(part)) {
+        // nothing to run, do not bother locating input
+        if args.json {
+            let records: Vec<Record> = parts
+                .iter()
+                .map(|&part| Record::solved((day.year, day.day, part), None, None, Duration::ZERO))
+                .collect();
+            print!("{}", report::json(&records));
+            return Ok(());
+        }
+        for part in parts {
+            println!("{} day {:>2} part {part}: not solved", day.year, day.day);
+        }
+        return Ok(());
+    }
+
+    let mut loader = utils::inputs::Loader::new(day.year, day.day);
+    if let Some(path) = args.input.or_else(input_var) {
+        loader = loader.path(path);
+    }
+    let source = loader.locate()?;
+    if source.is_example() {
+        eprintln!("no puzzle input, using {source}");
+    }
+    let input = source.read()?;
+    if args.alloc {
+        utils::alloc::enable();
+    }
+
+    if args.json {
+        // examples carry their own expected answers
+        let expected: Box<dyn Fn(u32) -> Option<String>> = match &source {
+            Source::Example(path) => {
+                let example = Example::parse(&std::fs::read_to_string(path)?);
+                Box::new(move |part| example.answer(&format!("part{part}")).map(String::from))
+            }
+            _ => {
+                let answers = Answers::load(&utils::answers::default_path())?;
+                Box::new(move |part| answers.get(day.year, day.day, part).map(String::from))
+            }
+        };
+        let input_kind = if source.is_example() {
+            report::Input::Example
+        } else {
+            report::Input::Puzzle
+        };
+        let timed = day.solve_timed(&input, &parts);
+        let records: Vec<Record> = parts
+            .iter()
+            .zip(timed.parts)
+            .map(|(&part, (answer, duration, alloc))| {
+                let record =
+                    Record::solved((day.year, day.day, part), answer, expected(part), duration)
+                        .with_input(input_kind);
+                if args.alloc && record.answer.is_some() {
+                    record.with_alloc(alloc)
+                } else {
+                    record
+                }
+            })
+            .collect();
+        print!("{}", report::json(&records));
+        return Ok(());
+    }
+
+    let timed = day.solve_timed(&input, &parts);
+    if args.alloc {
+        println!(
+            "{} day {:>2} parse:  {}",
+            day.year,
+            day.day,
+            alloc_summary(&timed.parse_alloc)
+        );
+    }
+    for (part, (answer, _, alloc)) in parts.iter().zip(timed.parts) {
+        let alloc = match &answer {
+            Some(_) if args.alloc => format!("  {}", alloc_summary(&alloc)),
+            _ => String::new(),
+        };
+        println!(
+            "{} day {:>2} part {part}: {}{alloc}",
+            day.year,
+            day.day,
+            answer.as_deref().unwrap_or("not solved")
+        );
+    }
+    Ok(())
+}
+
+/// allocations of a step, as printed by `run --alloc`
+fn alloc_summary(alloc: &utils::alloc::AllocStats) -> String {
+    format!(
+        "({} allocations, {} reallocations, {} allocated, {} peak heap)",
+        alloc.allocations,
+        alloc.reallocations,
+        bytes(alloc.bytes),
+        bytes(alloc.peak_heap)
+    )
+}
+
+/// format byte count with binary units
+fn bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+fn list() {
+    for day in days::DAYS {
+        let parts: Vec<String> = [1, 2]
+            .into_iter()
+            .filter(|&part| day.is_solved(part))
+            .map(|part| part.to_string())
+            .collect();
+        println!(
+            "{} day {:>2} parts [{}]",
+            day.year,
+            day.day,
+            parts.join(", ")
+        );
+    }
+}
+
+fn dispatch(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn std::error::Error>> {
+    match args.next().as_deref() {
+        Some("--log" | "-l") => {
+            let spec = args
+                .next()
+                .ok_or_else(|| usage("missing value for --log"))?;
+            let filter = utils::trace::Filter::parse(&spec).map_err(|e| usage(e.to_string()))?;
+            // nothing was logged yet, filter is not set
+            let _ = utils::trace::set_filter(filter);
+            dispatch(args)
+        }
+        Some("run") => run(RunArgs::parse(args)?),
+        Some("all") => all::all(all::AllArgs::parse(args)?),
+        Some("bench") => bench::bench(bench::BenchArgs::parse(args)?),
+        Some("verify") => verify::verify(verify::VerifyArgs::parse(args)?),
+        Some("new") => scaffold::new(scaffold::NewArgs::parse(args)?),
+        Some("stats") => stats::stats(stats::StatsArgs::parse(args)?),
+        Some("list") => {
+            list();
+            Ok(())
+        }
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Some(command) => Err(usage(format!("unknown command '{command}'"))),
+        None => Err(usage("missing command")),
+    }
+}
+
+fn main() {
+    if let Err(e) = dispatch(std::env::args().skip(1)) {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
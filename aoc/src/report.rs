@@ -0,0 +1,442 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use utils::alloc::AllocStats;
+
+/// Outcome of a part in a report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// answer matches expected answer
+    Pass,
+    /// answer differs from expected answer
+    Fail,
+    /// solved but there is no expected answer to compare with
+    NoAnswer,
+    Unsolved,
+    /// day panicked or failed before answering
+    Error,
+    Timeout,
+    /// day did not run, e.g. for lack of input
+    Skipped,
+}
+
+/// Input a part was solved with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Input {
+    /// actual puzzle input, passing it earns a star
+    Puzzle,
+    /// example input, used when puzzle input is missing
+    Example,
+}
+
+/// Result of a part, as emitted in reports
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub status: Status,
+    pub duration_ns: Option<u64>,
+    pub error: Option<String>,
+    /// input answer was computed from, when part ran
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<Input>,
+    /// allocations of the part, when counted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reallocations: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocated_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_heap_bytes: Option<u64>,
+}
+
+impl Record {
+    /// Record of a part which ran, comparing answer with expected answer
+    pub fn solved(
+        (year, day, part): (u32, u32, u32),
+        answer: Option<String>,
+        expected: Option<String>,
+        duration: Duration,
+    ) -> Self {
+        let status = match (&answer, &expected) {
+            (None, _) => Status::Unsolved,
+            (Some(_), None) => Status::NoAnswer,
+            (Some(answer), Some(expected)) if answer == expected => Status::Pass,
+            (Some(_), Some(_)) => Status::Fail,
+        };
+        Self {
+            year,
+            day,
+            part,
+            answer,
+            expected,
+            status,
+            duration_ns: (status != Status::Unsolved).then_some(duration.as_nanos() as u64),
+            error: None,
+            input: None,
+            allocations: None,
+            reallocations: None,
+            allocated_bytes: None,
+            peak_heap_bytes: None,
+        }
+    }
+
+    /// Record of a part which could not run
+    pub fn failed((year, day, part): (u32, u32, u32), status: Status, error: &str) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: None,
+            expected: None,
+            status,
+            duration_ns: None,
+            error: Some(error.to_string()),
+            input: None,
+            allocations: None,
+            reallocations: None,
+            allocated_bytes: None,
+            peak_heap_bytes: None,
+        }
+    }
+
+    pub fn with_input(self, input: Input) -> Self {
+        Self {
+            input: Some(input),
+            ..self
+        }
+    }
+
+    /// true if part passed on puzzle input
+    pub fn is_star(&self) -> bool {
+        self.status == Status::Pass && self.input != Some(Input::Example)
+    }
+
+    pub fn with_alloc(self, alloc: AllocStats) -> Self {
+        Self {
+            allocations: Some(alloc.allocations),
+            reallocations: Some(alloc.reallocations),
+            allocated_bytes: Some(alloc.bytes),
+            peak_heap_bytes: Some(alloc.peak_heap),
+            ..self
+        }
+    }
+
+    fn duration(&self) -> Option<Duration> {
+        self.duration_ns.map(Duration::from_nanos)
+    }
+}
+
+/// Report formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Junit,
+    Markdown,
+}
+
+impl Format {
+    pub fn parse(format: &str) -> Option<Self> {
+        match format {
+            "json" => Some(Format::Json),
+            "junit" => Some(Format::Junit),
+            "markdown" | "md" => Some(Format::Markdown),
+            _ => None,
+        }
+    }
+
+    pub fn render(self, records: &[Record]) -> String {
+        match self {
+            Format::Json => json(records),
+            Format::Junit => junit(records),
+            Format::Markdown => markdown(records),
+        }
+    }
+}
+
+pub fn json(records: &[Record]) -> String {
+    // records only hold plain fields, serializing cannot fail
+    serde_json::to_string_pretty(records).unwrap() + "\n"
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn seconds(records: &[&Record]) -> f64 {
+    records
+        .iter()
+        .filter_map(|record| record.duration())
+        .sum::<Duration>()
+        .as_secs_f64()
+}
+
+/// Distinct years of records, in records order
+fn years(records: &[Record]) -> Vec<u32> {
+    let mut years: Vec<u32> = records.iter().map(|record| record.year).collect();
+    years.dedup();
+    years
+}
+
+/// JUnit XML, a test suite per year and a test case per part
+pub fn junit(records: &[Record]) -> String {
+    let count = |records: &[&Record], status: &[Status]| {
+        records
+            .iter()
+            .filter(|record| status.contains(&record.status))
+            .count()
+    };
+    let all: Vec<&Record> = records.iter().collect();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"aoc\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+        all.len(),
+        count(&all, &[Status::Fail]),
+        count(&all, &[Status::Error, Status::Timeout]),
+        count(&all, &[Status::Unsolved, Status::Skipped]),
+        seconds(&all)
+    );
+    for year in years(records) {
+        let suite: Vec<&Record> = records.iter().filter(|r| r.year == year).collect();
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{year}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+            suite.len(),
+            count(&suite, &[Status::Fail]),
+            count(&suite, &[Status::Error, Status::Timeout]),
+            count(&suite, &[Status::Unsolved, Status::Skipped]),
+            seconds(&suite)
+        );
+        for record in suite {
+            let _ = write!(
+                xml,
+                "    <testcase classname=\"aoc.{year}.d{}\" name=\"part{}\" time=\"{:.6}\"",
+                record.day,
+                record.part,
+                seconds(&[record])
+            );
+            let error = xml_escape(record.error.as_deref().unwrap_or_default());
+            let body = match record.status {
+                Status::Pass | Status::NoAnswer => None,
+                Status::Fail => Some(format!(
+                    "<failure message=\"expected {}\"/>",
+                    xml_escape(record.expected.as_deref().unwrap_or_default())
+                )),
+                Status::Unsolved => Some("<skipped message=\"not solved\"/>".to_string()),
+                Status::Skipped => Some(format!("<skipped message=\"{error}\"/>")),
+                Status::Error => Some(format!("<error message=\"{error}\"/>")),
+                Status::Timeout => Some(format!("<error type=\"timeout\" message=\"{error}\"/>")),
+            };
+            let output = record
+                .answer
+                .as_deref()
+                .map(|answer| format!("<system-out>{}</system-out>", xml_escape(answer)));
+            if body.is_none() && output.is_none() {
+                xml.push_str("/>\n");
+            } else {
+                xml.push_str(">\n");
+                for element in [body, output].into_iter().flatten() {
+                    let _ = writeln!(xml, "      {element}");
+                }
+                xml.push_str("    </testcase>\n");
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// Markdown cell of a part
+fn cell(record: Option<&Record>) -> String {
+    let Some(record) = record else {
+        return "-".to_string();
+    };
+    let answer = record.answer.as_deref().unwrap_or_default();
+    let error = record.error.as_deref().unwrap_or_default();
+    let cell = match record.status {
+        Status::Pass if record.is_star() => format!("★ `{answer}`"),
+        Status::Pass => format!("ex `{answer}`"),
+        Status::NoAnswer => format!("`{answer}`"),
+        Status::Fail => format!(
+            "✗ `{answer}`, expected `{}`",
+            record.expected.as_deref().unwrap_or_default()
+        ),
+        Status::Unsolved => "-".to_string(),
+        Status::Error => format!("error: {error}"),
+        Status::Timeout => "timeout".to_string(),
+        Status::Skipped => format!("skipped: {error}"),
+    };
+    // a pipe would end the cell
+    cell.replace('|', "\\|")
+}
+
+/// Markdown document with a table of stars, answers and timings per year
+pub fn markdown(records: &[Record]) -> String {
+    let mut md = String::from("# Advent of Code\n");
+    for year in years(records) {
+        let year_records: Vec<&Record> = records.iter().filter(|r| r.year == year).collect();
+        let stars = year_records
+            .iter()
+            .filter(|record| record.is_star())
+            .count();
+        // passes on examples are no stars, count them apart
+        let examples = year_records
+            .iter()
+            .filter(|record| record.status == Status::Pass && !record.is_star())
+            .count();
+        let examples = match examples {
+            0 => String::new(),
+            n => format!(", {n} ex"),
+        };
+        let _ = write!(
+            md,
+            "\n## {year}: {stars} ★{examples}\n\n| Day | Part 1 | Part 2 | Time |\n|----:|--------|--------|-----:|\n"
+        );
+
+        let mut days: Vec<u32> = year_records.iter().map(|record| record.day).collect();
+        days.dedup();
+        for day in days {
+            let day_records: Vec<&Record> = year_records
+                .iter()
+                .copied()
+                .filter(|record| record.day == day)
+                .collect();
+            let part = |part: u32| day_records.iter().copied().find(|r| r.part == part);
+            let time = if day_records.iter().any(|record| record.duration().is_some()) {
+                format!("{:.2?}", Duration::from_secs_f64(seconds(&day_records)))
+            } else {
+                "-".to_string()
+            };
+            let _ = writeln!(
+                md,
+                "| {day} | {} | {} | {time} |",
+                cell(part(1)),
+                cell(part(2))
+            );
+        }
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        let ms = Duration::from_millis;
+        vec![
+            Record::solved((2023, 7, 1), None, None, ms(0)),
+            Record::solved(
+                (2023, 7, 2),
+                Some("5905".to_string()),
+                Some("5905".to_string()),
+                ms(2),
+            )
+            .with_input(Input::Puzzle),
+            Record::solved(
+                (2023, 17, 1),
+                Some("927".to_string()),
+                Some("928".to_string()),
+                ms(3),
+            ),
+            Record::failed((2023, 17, 2), Status::Error, "not yet <implemented>"),
+            Record::solved((2025, 1, 1), Some("1|2".to_string()), None, ms(1)).with_alloc(
+                AllocStats {
+                    allocations: 3,
+                    reallocations: 1,
+                    bytes: 4096,
+                    peak_heap: 2048,
+                },
+            ),
+            Record::failed((2025, 1, 2), Status::Timeout, "timed out after 60s"),
+            Record::solved(
+                (2025, 2, 1),
+                Some("3".to_string()),
+                Some("3".to_string()),
+                ms(1),
+            )
+            .with_input(Input::Example),
+        ]
+    }
+
+    #[test]
+    fn status() {
+        let records = records();
+        let status: Vec<Status> = records.iter().map(|record| record.status).collect();
+        assert_eq!(
+            status,
+            vec![
+                Status::Unsolved,
+                Status::Pass,
+                Status::Fail,
+                Status::Error,
+                Status::NoAnswer,
+                Status::Timeout,
+                Status::Pass
+            ]
+        );
+        assert_eq!(records[0].duration_ns, None);
+        assert_eq!(records[1].duration_ns, Some(2_000_000));
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let records = records();
+        let json = json(&records);
+        assert!(json.contains("\"status\": \"no_answer\""));
+        assert!(json.contains("\"peak_heap_bytes\": 2048"));
+        assert!(json.contains("\"input\": \"example\""));
+        assert_eq!(json.matches("\"allocations\"").count(), 1);
+        assert!(json.contains("\"reallocations\": 1"));
+        let parsed: Vec<Record> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, records);
+    }
+
+    #[test]
+    fn junit_report() {
+        let xml = junit(&records());
+        assert!(xml.contains(
+            "<testsuites name=\"aoc\" tests=\"7\" failures=\"1\" errors=\"2\" skipped=\"1\" time=\"0.007000\">"
+        ));
+        assert!(xml.contains("<testsuite name=\"2025\" tests=\"3\" failures=\"0\" errors=\"1\""));
+        assert!(xml.contains("<failure message=\"expected 928\"/>"));
+        assert!(xml.contains("<error message=\"not yet &lt;implemented&gt;\"/>"));
+        assert!(xml.contains("<skipped message=\"not solved\"/>"));
+        assert!(xml.contains("<system-out>5905</system-out>"));
+    }
+
+    #[test]
+    fn markdown_report() {
+        let md = markdown(&records());
+        assert!(md.contains("## 2023: 1 ★\n"));
+        assert!(md.contains("| 7 | - | ★ `5905` | 2.00ms |\n"));
+        assert!(md.contains(
+            "| 17 | ✗ `927`, expected `928` | error: not yet <implemented> | 3.00ms |\n"
+        ));
+        // passing an example earns no star
+        assert!(md.contains("## 2025: 0 ★, 1 ex\n"));
+        assert!(md.contains("| 1 | `1\\|2` | timeout | 1.00ms |\n"));
+        assert!(md.contains("| 2 | ex `3` | - | 1.00ms |\n"));
+    }
+}
@@ -0,0 +1,315 @@
+use std::path::{Path, PathBuf};
+
+use crate::usage;
+
+/// Parsed `new` arguments
+pub struct NewArgs {
+    year: u32,
+    day: u32,
+}
+
+impl NewArgs {
+    pub fn parse(
+        mut args: impl Iterator<Item = String>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut number = |what: &str| -> Result<u32, Box<dyn std::error::Error>> {
+            let arg = args
+                .next()
+                .ok_or_else(|| usage(format!("missing {what}")))?;
+            arg.parse()
+                .map_err(|_| usage(format!("invalid {what} '{arg}'")))
+        };
+        let year = number("year")?;
+        let day = number("day")?;
+        if !(1..=25).contains(&day) {
+            return Err(usage(format!("invalid day '{day}'")));
+        }
+        if let Some(arg) = args.next() {
+            return Err(usage(format!("unexpected argument '{arg}'")));
+        }
+        Ok(Self { year, day })
+    }
+}
+
+fn cargo_toml(year: u32, day: u32) -> String {
+    format!(
+        r#"[package]
+name = "aoc{year}-d{day}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+utils = {{ path = "../../utils" }}
+"#
+    )
+}
+
+fn lib_rs(year: u32, day: u32) -> String {
+    format!(
+        r#"use utils::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {{
+    type Input = Vec<String>;
+
+    const SOLVED: [bool; 2] = [false, false];
+
+    fn parse(input: &str) -> Self::Input {{
+        input.lines().map(String::from).collect()
+    }}
+}}
+
+#[cfg(test)]
+mod test {{
+    use super::*;
+
+    utils::example_tests!(Puzzle; example);
+    utils::answer_tests!(Puzzle; {year}, {day});
+}}
+"#
+    )
+}
+
+/// Example without known answers yet, add `partN: <answer>` lines above `---`
+const EXAMPLE: &str = "---\n";
+
+/// Insert `entry` among lines keyed by (year, day), keeping keys sorted.
+///
+/// When no line of that year exists, a new year group is opened with
+/// `header` (or a blank line when there is no header).
+fn insert_sorted(
+    text: &str,
+    key: impl Fn(&str) -> Option<(u32, u32)>,
+    (year, day): (u32, u32),
+    entry: &str,
+    header: Option<&str>,
+) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let keyed: Vec<(usize, (u32, u32))> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| key(line).map(|k| (idx, k)))
+        .collect();
+    if keyed.iter().any(|&(_, k)| k == (year, day)) {
+        return Err(format!("{year} day {day} is already registered"));
+    }
+
+    let same_year = keyed.iter().filter(|(_, (y, _))| *y == year);
+    if let Some(&(last, _)) = same_year.clone().next_back() {
+        let pos = same_year
+            .clone()
+            .find(|(_, (_, d))| *d > day)
+            .map_or(last + 1, |&(idx, _)| idx);
+        lines.insert(pos, entry);
+    } else if let Some(&(prev, _)) = keyed.iter().rfind(|(_, (y, _))| *y < year) {
+        // new year group after previous years
+        let group = [header.unwrap_or(""), entry];
+        lines.splice(prev + 1..prev + 1, group);
+    } else if let Some(&(next, (next_year, _))) = keyed.first() {
+        // new year group before every other years, and before the header
+        // of the first one if it has one
+        match header {
+            Some(header) => {
+                let next_header = header.replace(&year.to_string(), &next_year.to_string());
+                let pos = match next.checked_sub(1) {
+                    Some(pos) if lines[pos] == next_header => pos,
+                    _ => next,
+                };
+                lines.splice(pos..pos, [header, entry])
+            }
+            None => lines.splice(next..next, [entry, ""]),
+        };
+    } else {
+        return Err("no registered day to insert next to".to_string());
+    }
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Ok(text)
+}
+
+/// key of `aoc<year>-d<day> = ...` dependency lines
+fn dependency_key(line: &str) -> Option<(u32, u32)> {
+    let (name, _) = line.strip_prefix("aoc")?.split_once(" = ")?;
+    let (year, day) = name.split_once("-d")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// key of `day!(<year>, <day>, ...)` registry lines
+fn registry_key(line: &str) -> Option<(u32, u32)> {
+    let mut args = line.trim().strip_prefix("day!(")?.split(',');
+    let year = args.next()?.trim().parse().ok()?;
+    let day = args.next()?.trim().parse().ok()?;
+    Some((year, day))
+}
+
+/// Create day crate in repository `root` and register it with the runner, return crate directory
+pub fn create(root: &Path, year: u32, day: u32) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let dir = root.join(format!("{year}")).join(format!("d{day}"));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    // update runner first, nothing is left behind if day is already registered
+    let manifest = root.join("aoc").join("Cargo.toml");
+    let registry = root.join("aoc").join("src").join("days.rs");
+    let manifest_text = insert_sorted(
+        &std::fs::read_to_string(&manifest)?,
+        dependency_key,
+        (year, day),
+        &format!("aoc{year}-d{day} = {{ path = \"../{year}/d{day}\" }}"),
+        None,
+    )?;
+    let registry_text = insert_sorted(
+        &std::fs::read_to_string(&registry)?,
+        registry_key,
+        (year, day),
+        &format!("    day!({year}, {day}, aoc{year}_d{day}::Puzzle),"),
+        Some(&format!("    // -- {year} --")),
+    )?;
+
+    std::fs::create_dir_all(dir.join("src"))?;
+    std::fs::write(dir.join("Cargo.toml"), cargo_toml(year, day))?;
+    std::fs::write(dir.join("src").join("lib.rs"), lib_rs(year, day))?;
+    std::fs::write(dir.join("example"), EXAMPLE)?;
+    std::fs::write(manifest, manifest_text)?;
+    std::fs::write(registry, registry_text)?;
+    Ok(dir)
+}
+
+pub fn new(args: NewArgs) -> Result<(), Box<dyn std::error::Error>> {
+    // aoc lives at the root of the repository
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let dir = create(root, args.year, args.day)?;
+    println!("created {}", dir.display());
+    println!("registered {} day {} in aoc", args.year, args.day);
+    println!(
+        "next: paste the example in {}, with its answers above '---'",
+        dir.join("example").display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "[dependencies]
+utils = { path = \"../utils\" }
+
+aoc2023-d1 = { path = \"../2023/d1\" }
+aoc2023-d3 = { path = \"../2023/d3\" }
+
+aoc2025-d1 = { path = \"../2025/d1\" }
+";
+
+    const REGISTRY: &str = "pub static DAYS: &[Day] = &[
+    // -- 2023 --
+    day!(2023, 1, aoc2023_d1::Puzzle),
+    day!(2023, 3, aoc2023_d3::Puzzle),
+    // -- 2025 --
+    day!(2025, 1, aoc2025_d1::Puzzle),
+];
+";
+
+    fn dependency(year: u32, day: u32) -> Result<String, String> {
+        insert_sorted(
+            MANIFEST,
+            dependency_key,
+            (year, day),
+            &format!("aoc{year}-d{day} = {{ path = \"../{year}/d{day}\" }}"),
+            None,
+        )
+    }
+
+    fn registry(year: u32, day: u32) -> Result<String, String> {
+        insert_sorted(
+            REGISTRY,
+            registry_key,
+            (year, day),
+            &format!("    day!({year}, {day}, aoc{year}_d{day}::Puzzle),"),
+            Some(&format!("    // -- {year} --")),
+        )
+    }
+
+    #[test]
+    fn dependencies() {
+        assert!(dependency(2023, 2)
+            .unwrap()
+            .contains("d1\" }\naoc2023-d2 = { path = \"../2023/d2\" }\naoc2023-d3"));
+        assert!(dependency(2023, 4)
+            .unwrap()
+            .contains("d3\" }\naoc2023-d4 = { path = \"../2023/d4\" }\n\naoc2025"));
+        assert!(dependency(2024, 1)
+            .unwrap()
+            .contains("d3\" }\n\naoc2024-d1 = { path = \"../2024/d1\" }\n\naoc2025"));
+        assert!(dependency(2022, 1)
+            .unwrap()
+            .contains("\n\naoc2022-d1 = { path = \"../2022/d1\" }\n\naoc2023-d1"));
+        assert!(dependency(2023, 3).is_err());
+    }
+
+    #[test]
+    fn registry_lines() {
+        assert!(registry(2025, 2).unwrap().ends_with(
+            "day!(2025, 1, aoc2025_d1::Puzzle),\n    day!(2025, 2, aoc2025_d2::Puzzle),\n];\n"
+        ));
+        assert!(registry(2024, 5).unwrap().contains(
+            "aoc2023_d3::Puzzle),\n    // -- 2024 --\n    day!(2024, 5, aoc2024_d5::Puzzle),\n    // -- 2025 --"
+        ));
+        assert!(registry(2022, 5).unwrap().contains(
+            "&[\n    // -- 2022 --\n    day!(2022, 5, aoc2022_d5::Puzzle),\n    // -- 2023 --"
+        ));
+        assert!(registry(2025, 1).is_err());
+    }
+
+    #[test]
+    fn registry_without_headers() {
+        let registry = |text| {
+            insert_sorted(
+                text,
+                registry_key,
+                (2022, 5),
+                "day!(2022, 5, aoc2022_d5::Puzzle),",
+                Some("// -- 2022 --"),
+            )
+        };
+        // first entry on first line
+        assert_eq!(
+            registry("day!(2023, 1, aoc2023_d1::Puzzle),\n").unwrap(),
+            "// -- 2022 --\nday!(2022, 5, aoc2022_d5::Puzzle),\nday!(2023, 1, aoc2023_d1::Puzzle),\n"
+        );
+        // line before first entry is not a header
+        assert_eq!(
+            registry("&[\nday!(2023, 1, aoc2023_d1::Puzzle),\n").unwrap(),
+            "&[\n// -- 2022 --\nday!(2022, 5, aoc2022_d5::Puzzle),\nday!(2023, 1, aoc2023_d1::Puzzle),\n"
+        );
+    }
+
+    #[test]
+    fn create_day() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        std::fs::write(root.join("aoc").join("Cargo.toml"), MANIFEST).unwrap();
+        std::fs::write(root.join("aoc").join("src").join("days.rs"), REGISTRY).unwrap();
+
+        let dir = create(&root, 2025, 2).unwrap();
+        assert!(dir.ends_with("2025/d2"));
+        let lib = std::fs::read_to_string(dir.join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Puzzle"));
+        assert!(lib.contains("answer_tests!(Puzzle; 2025, 2)"));
+        let manifest = std::fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"aoc2025-d2\""));
+        assert!(
+            std::fs::read_to_string(root.join("aoc").join("src").join("days.rs"))
+                .unwrap()
+                .contains("day!(2025, 2, aoc2025_d2::Puzzle)")
+        );
+
+        // day already exists
+        assert!(create(&root, 2025, 2).is_err());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
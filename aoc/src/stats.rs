@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use utils::asciimap::AsciiMap;
+use utils::inputs::{self, Loader};
+
+use crate::usage;
+
+/// Parsed `stats` arguments
+pub struct StatsArgs {
+    year: u32,
+    day: u32,
+    input: Option<PathBuf>,
+}
+
+impl StatsArgs {
+    pub fn parse(
+        mut args: impl Iterator<Item = String>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut number = |what: &str| -> Result<u32, Box<dyn std::error::Error>> {
+            let arg = args
+                .next()
+                .ok_or_else(|| usage(format!("missing {what}")))?;
+            arg.parse()
+                .map_err(|_| usage(format!("invalid {what} '{arg}'")))
+        };
+        let year = number("year")?;
+        let day = number("day")?;
+
+        let mut stats = Self {
+            year,
+            day,
+            input: None,
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    let path = args
+                        .next()
+                        .ok_or_else(|| usage(format!("missing value for {arg}")))?;
+                    stats.input = Some(PathBuf::from(path));
+                }
+                _ => return Err(usage(format!("unexpected argument '{arg}'"))),
+            }
+        }
+        Ok(stats)
+    }
+}
+
+/// Integers found in input, see [`utils::inputs::integer_tokens`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Integers {
+    pub count: usize,
+    /// (min, max) of integers fitting in an `i128`, if any
+    pub range: Option<(i128, i128)>,
+    pub negative: usize,
+    /// integers not fitting in an `i128`
+    pub overflow: usize,
+}
+
+impl Integers {
+    /// `None` if input holds no integer
+    fn scan(input: &str) -> Option<Self> {
+        let mut integers = Self {
+            count: 0,
+            range: None,
+            negative: 0,
+            overflow: 0,
+        };
+        for token in inputs::integer_tokens(input) {
+            integers.count += 1;
+            let Ok(n) = token.parse::<i128>() else {
+                integers.overflow += 1;
+                continue;
+            };
+            integers.range = Some(
+                integers
+                    .range
+                    .map_or((n, n), |(min, max)| (min.min(n), max.max(n))),
+            );
+            if n < 0 {
+                integers.negative += 1;
+            }
+        }
+        (integers.count > 0).then_some(integers)
+    }
+}
+
+/// Characteristics of a puzzle input
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    pub bytes: usize,
+    pub lines: usize,
+    /// line length and number of lines of that length
+    pub line_lengths: BTreeMap<usize, usize>,
+    /// groups of lines separated by blank lines
+    pub sections: usize,
+    /// (width, height) of sections shaped as a grid
+    pub grids: Vec<(usize, usize)>,
+    /// char counts of grid sections
+    pub chars: BTreeMap<char, usize>,
+    pub integers: Option<Integers>,
+}
+
+impl Profile {
+    pub fn new(input: &str) -> Self {
+        let lines: Vec<&str> = input.lines().collect();
+        let mut line_lengths = BTreeMap::new();
+        for line in &lines {
+            *line_lengths.entry(line.len()).or_insert(0) += 1;
+        }
+
+        let sections: Vec<&[&str]> = lines
+            .split(|line| line.trim().is_empty())
+            .filter(|section| !section.is_empty())
+            .collect();
+        let mut grids = vec![];
+        let mut chars = BTreeMap::new();
+        for section in sections.iter().filter(|section| Self::is_grid(section)) {
+            let map = AsciiMap::from_multi_lines(section.join("\n"));
+            grids.push(map.size());
+            for (c, _) in map.iter() {
+                *chars.entry(c).or_insert(0) += 1;
+            }
+        }
+
+        Self {
+            bytes: input.len(),
+            lines: lines.len(),
+            line_lengths,
+            sections: sections.len(),
+            grids,
+            chars,
+            integers: Integers::scan(input),
+        }
+    }
+
+    /// at least 2 ASCII lines of the same length, without spaces
+    fn is_grid(section: &[&str]) -> bool {
+        section.len() > 1
+            && section[0].len() > 1
+            && section.iter().all(|line| {
+                line.len() == section[0].len()
+                    && line.is_ascii()
+                    && !line.contains(char::is_whitespace)
+            })
+    }
+}
+
+/// print `<count> x <what>` pairs, sorted by decreasing count
+fn histogram<K: std::fmt::Debug>(counts: impl Iterator<Item = (K, usize)>) {
+    let mut counts: Vec<(K, usize)> = counts.collect();
+    counts.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+    for (what, count) in counts {
+        println!("  {what:>6?}  {count}");
+    }
+}
+
+pub fn stats(args: StatsArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut loader = Loader::new(args.year, args.day);
+    if let Some(path) = args.input.or_else(crate::input_var) {
+        loader = loader.path(path);
+    }
+    let source = loader.locate()?;
+    let profile = Profile::new(&source.read()?);
+
+    println!("{} day {} input: {source}", args.year, args.day);
+    println!("bytes     {}", profile.bytes);
+    println!("lines     {}", profile.lines);
+    println!("sections  {}", profile.sections);
+    if let (Some((&min, _)), Some((&max, _))) = (
+        profile.line_lengths.first_key_value(),
+        profile.line_lengths.last_key_value(),
+    ) {
+        let total: usize = profile.line_lengths.iter().map(|(len, n)| len * n).sum();
+        let mean = total as f64 / profile.lines as f64;
+        println!("line length min {min}, max {max}, mean {mean:.1}");
+        // a full distribution is only readable with few distinct lengths
+        if profile.line_lengths.len() <= 10 {
+            histogram(profile.line_lengths.iter().map(|(&len, &n)| (len, n)));
+        }
+    }
+
+    match profile.integers {
+        Some(integers) => {
+            let range = match integers.range {
+                Some((min, max)) => format!(" in {min}..={max}"),
+                None => String::new(),
+            };
+            println!(
+                "integers  {}{range}, {} negative",
+                integers.count, integers.negative
+            );
+            if integers.overflow > 0 {
+                println!("  {} do not fit in 128 bits", integers.overflow);
+            }
+        }
+        None => println!("integers  none"),
+    }
+
+    if !profile.grids.is_empty() {
+        let mut sizes = BTreeMap::new();
+        for size in &profile.grids {
+            *sizes.entry(*size).or_insert(0) += 1;
+        }
+        let sizes: Vec<String> = sizes
+            .iter()
+            .map(|((w, h), n)| format!("{n} x {w}x{h}"))
+            .collect();
+        println!("grids     {}", sizes.join(", "));
+        histogram(profile.chars.iter().map(|(&c, &n)| (c, n)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        let integers =
+            Integers::scan("p=0,4 v=-3,3\nx-1 99999999999999999999999999999999999999999").unwrap();
+        assert_eq!(integers.count, 6);
+        assert_eq!(integers.range, Some((-3, 4)));
+        assert_eq!(integers.negative, 1);
+        assert_eq!(integers.overflow, 1);
+        assert_eq!(Integers::scan("#.#\n"), None);
+
+        let integers = Integers::scan("99999999999999999999999999999999999999999").unwrap();
+        assert_eq!((integers.count, integers.overflow), (1, 1));
+        assert_eq!(integers.range, None);
+    }
+
+    #[test]
+    fn grids() {
+        let profile = Profile::new("#.##\n..#.\n\n#.\n.#\n\nseeds: 79 14\n");
+        assert_eq!(profile.lines, 7);
+        assert_eq!(profile.sections, 3);
+        assert_eq!(profile.grids, vec![(4, 2), (2, 2)]);
+        assert_eq!(profile.chars[&'#'], 6);
+        assert_eq!(profile.chars[&'.'], 6);
+        assert_eq!(profile.line_lengths[&0], 2);
+        assert_eq!(profile.line_lengths[&12], 1);
+    }
+}
@@ -0,0 +1,244 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use utils::answers::Answers;
+use utils::inputs::{InputError, Loader};
+
+use crate::days::{self, Day};
+use crate::usage;
+
+/// Parsed `verify` arguments
+pub struct VerifyArgs {
+    /// only verify this year
+    year: Option<u32>,
+    /// only verify this day of year
+    day: Option<u32>,
+    answers: Option<PathBuf>,
+}
+
+impl VerifyArgs {
+    pub fn parse(
+        mut args: impl Iterator<Item = String>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut verify = Self {
+            year: None,
+            day: None,
+            answers: None,
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--answers" | "-a" => {
+                    let path = args
+                        .next()
+                        .ok_or_else(|| usage(format!("missing value for {arg}")))?;
+                    verify.answers = Some(PathBuf::from(path));
+                }
+                _ => {
+                    let number = arg
+                        .parse()
+                        .map_err(|_| usage(format!("unknown argument '{arg}'")))?;
+                    if verify.year.is_none() {
+                        verify.year = Some(number);
+                    } else if verify.day.is_none() {
+                        verify.day = Some(number);
+                    } else {
+                        return Err(usage(format!("unexpected argument '{arg}'")));
+                    }
+                }
+            }
+        }
+        Ok(verify)
+    }
+
+    fn selects(&self, day: &Day) -> bool {
+        self.year.is_none_or(|year| year == day.year) && self.day.is_none_or(|d| d == day.day)
+    }
+}
+
+/// Outcome of verifying a part
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// solved but there is no known answer to compare with
+    NoAnswer,
+    MissingInput,
+    /// solver panicked, with panic message
+    Panicked(String),
+}
+
+impl Status {
+    fn compare(answer: &str, expected: Option<&str>) -> Self {
+        match expected {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.to_string(),
+            },
+            None => Status::NoAnswer,
+        }
+    }
+}
+
+/// Verification of a solved part
+pub struct Check {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub status: Status,
+    /// computed answer, if input was found
+    pub answer: Option<String>,
+}
+
+/// Solve every solved part of day on its real input and compare with known answers
+pub fn verify_day(day: &Day, answers: &Answers) -> Result<Vec<Check>, InputError> {
+    let parts: Vec<u32> = [1, 2]
+        .into_iter()
+        .filter(|&part| day.is_solved(part))
+        .collect();
+    let check = |part: u32, status: Status, answer: Option<String>| Check {
+        year: day.year,
+        day: day.day,
+        part,
+        status,
+        answer,
+    };
+
+    // examples would not match known answers, only use real inputs
+    let input = match Loader::new(day.year, day.day).examples(false).load() {
+        Ok(input) => input,
+        Err(InputError::NotFound { .. }) => {
+            return Ok(parts
+                .into_iter()
+                .map(|part| check(part, Status::MissingInput, None))
+                .collect())
+        }
+        Err(e) => return Err(e),
+    };
+
+    // a failing day must not abort verification of the others
+    let solved = match panic::catch_unwind(AssertUnwindSafe(|| day.solve(&input, &parts))) {
+        Ok(solved) => solved,
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+            return Ok(parts
+                .into_iter()
+                .map(|part| check(part, Status::Panicked(message.clone()), None))
+                .collect());
+        }
+    };
+
+    Ok(parts
+        .iter()
+        .zip(solved)
+        .map(|(&part, answer)| {
+            // parts are solved, an answer is always given
+            let answer = answer.unwrap_or_default();
+            let status = Status::compare(&answer, answers.get(day.year, day.day, part));
+            check(part, status, Some(answer))
+        })
+        .collect())
+}
+
+/// message given to `panic!`, if it is a string
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+pub fn verify(args: VerifyArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let path = args
+        .answers
+        .clone()
+        .unwrap_or_else(utils::answers::default_path);
+    let answers = Answers::load(&path)?;
+
+    println!("year  day  part  status         answer");
+    let (mut passed, mut failed, mut missing, mut unknown) = (0, 0, 0, 0);
+    for day in days::DAYS.iter().filter(|day| args.selects(day)) {
+        for check in verify_day(day, &answers)? {
+            let (status, detail) = match &check.status {
+                Status::Pass => {
+                    passed += 1;
+                    ("pass", String::new())
+                }
+                Status::Fail { expected } => {
+                    failed += 1;
+                    ("FAIL", format!(" (expected {expected})"))
+                }
+                Status::NoAnswer => {
+                    unknown += 1;
+                    ("no answer", String::new())
+                }
+                Status::MissingInput => {
+                    missing += 1;
+                    ("missing input", String::new())
+                }
+                Status::Panicked(message) => {
+                    failed += 1;
+                    ("PANIC", format!(" ({message})"))
+                }
+            };
+            println!(
+                "{}  {:>3}  {:>4}  {status:<13}  {}{detail}",
+                check.year,
+                check.day,
+                check.part,
+                check.answer.as_deref().unwrap_or("-")
+            );
+        }
+    }
+    println!(
+        "{passed} passed, {failed} failed, {missing} missing input, {unknown} without answer in {}",
+        path.display()
+    );
+
+    if failed > 0 {
+        Err(format!("{failed} answers differ from known answers or panicked").into())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare() {
+        assert_eq!(Status::compare("928", Some("928")), Status::Pass);
+        assert_eq!(
+            Status::compare("927", Some("928")),
+            Status::Fail {
+                expected: "928".to_string()
+            }
+        );
+        assert_eq!(Status::compare("928", None), Status::NoAnswer);
+    }
+
+    #[test]
+    fn panics() {
+        let payload = panic::catch_unwind(|| panic!("no path to {}", 17)).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "no path to 17");
+        let payload = panic::catch_unwind(|| panic!("todo")).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "todo");
+    }
+
+    #[test]
+    fn selection() {
+        let args = VerifyArgs::parse(["2023".to_string()].into_iter()).unwrap();
+        assert!(args.selects(days::find(2023, 17).unwrap()));
+        assert!(!args.selects(days::find(2025, 1).unwrap()));
+
+        let args = VerifyArgs::parse(["2025", "1"].map(String::from).into_iter()).unwrap();
+        assert!(args.selects(days::find(2025, 1).unwrap()));
+        assert!(!args.selects(days::find(2025, 2).unwrap()));
+    }
+}
@@ -0,0 +1,159 @@
+//! Counting global allocator.
+//!
+//! Binaries opt in by installing [`Counting`], which forwards to the system
+//! allocator and, once [`enable`]d, counts allocations, reallocations and
+//! heap bytes:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOC: utils::alloc::Counting = utils::alloc::Counting;
+//! ```
+//!
+//! Counters are process wide, [`measure`] is only meaningful while no other
+//! thread allocates.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicU64, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static REALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+/// heap bytes live since counting was enabled, negative when memory
+/// allocated before is freed
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+/// System allocator counting allocations while enabled
+pub struct Counting;
+
+impl Counting {
+    fn grow(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        Self::extend(size);
+    }
+
+    /// count `size` more heap bytes
+    fn extend(size: usize) {
+        ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn shrink(size: usize) {
+        LIVE.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        if ENABLED.load(Ordering::Relaxed) {
+            Self::shrink(layout.size());
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() && ENABLED.load(Ordering::Relaxed) {
+            // only growth counts as allocated bytes
+            REALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            if new_size > layout.size() {
+                Self::extend(new_size - layout.size());
+            } else {
+                Self::shrink(layout.size() - new_size);
+            }
+        }
+        new
+    }
+}
+
+/// Start counting, allocations are not counted by default
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Allocations made by a measured step
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    /// allocations resized in place or moved, not counted in `allocations`
+    pub reallocations: u64,
+    /// total bytes allocated, whether freed or not
+    pub bytes: u64,
+    /// most heap bytes held at once above those live when step started
+    pub peak_heap: u64,
+}
+
+/// Run `f` and count its allocations, stats are zero when counting is not
+/// enabled or [`Counting`] is not the global allocator
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, AllocStats) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let reallocations = REALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        reallocations: REALLOCATIONS.load(Ordering::Relaxed) - reallocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak_heap: (PEAK.load(Ordering::Relaxed) - live).max(0) as u64,
+    };
+    (result, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOC: Counting = Counting;
+
+    #[test]
+    fn counts() {
+        enable();
+        // other tests may allocate concurrently, only check lower bounds
+        let (sum, stats) = measure(|| {
+            let big = vec![1u8; 1 << 20];
+            let small = vec![1u8; 1 << 10];
+            big.iter().chain(&small).map(|&b| b as usize).sum::<usize>()
+        });
+        assert_eq!(sum, (1 << 20) + (1 << 10));
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= (1 << 20) + (1 << 10));
+        assert!(stats.peak_heap >= (1 << 20) + (1 << 10));
+
+        // growing a vector counts added bytes only
+        let (_, stats) = measure(|| {
+            let mut v: Vec<u8> = Vec::with_capacity(1 << 16);
+            v.reserve_exact(1 << 17);
+            v
+        });
+        assert!(stats.allocations >= 1);
+        assert!(stats.reallocations >= 1);
+        assert!(stats.bytes >= (1 << 16) + (1 << 16));
+    }
+}
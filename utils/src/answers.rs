@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::inputs::{InputError, Loader};
+use crate::solution::{self, Solution};
+
+/// Environment variable naming the answers file to use instead of the default one
+pub const ANSWERS_VAR: &str = "AOC_ANSWERS";
+
+/// Known-good answers, keyed by (year, day, part).
+///
+/// Answers files hold one answer per line, blank lines and lines starting
+/// with `#` are ignored:
+///
+/// ```text
+/// # year day part: answer
+/// 2023 d17 part1: 928
+/// 2023 d17 part2: 1104
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u32, u32, u32), String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, AnswersError> {
+        let mut answers = BTreeMap::new();
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || AnswersError::Invalid(idx + 1, line.to_string());
+            let (key, answer) = line.split_once(':').ok_or_else(invalid)?;
+            let key = Self::parse_key(key).ok_or_else(invalid)?;
+            answers.insert(key, answer.trim().to_string());
+        }
+        Ok(Self { answers })
+    }
+
+    /// parse `<year> d<day> part<part>`
+    fn parse_key(key: &str) -> Option<(u32, u32, u32)> {
+        let mut fields = key.split_whitespace();
+        let year = fields.next()?.parse().ok()?;
+        let day = fields.next()?.strip_prefix('d')?.parse().ok()?;
+        let part = fields.next()?.strip_prefix("part")?.parse().ok()?;
+        fields.next().is_none().then_some((year, day, part))
+    }
+
+    /// Load answers file, a missing file holds no answers
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(AnswersError::Io(path.to_path_buf(), e)),
+        }
+    }
+
+    /// return expected answer, if known
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, year: u32, day: u32, part: u32, answer: impl Into<String>) {
+        self.answers.insert((year, day, part), answer.into());
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+/// Answers file given in `AOC_ANSWERS`, else `answers` at the root of the repository
+pub fn default_path() -> PathBuf {
+    std::env::var_os(ANSWERS_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            // utils lives at the root of the repository
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .unwrap()
+                .join("answers")
+        })
+}
+
+/// Check answer of a part on puzzle input against known answers, panic if
+/// they differ. Nothing is checked when input, known answer or solution of
+/// the part is missing.
+pub fn check<S: Solution>(year: u32, day: u32, part: u32) {
+    let skip = |reason: &str| eprintln!("{year} day {day} part {part}: skipped, {reason}");
+    let answers = Answers::load(&default_path()).unwrap();
+    let Some(expected) = answers.get(year, day, part) else {
+        return skip("no known answer");
+    };
+    let input = match Loader::new(year, day).examples(false).load() {
+        Ok(input) => input,
+        Err(InputError::NotFound { .. }) => return skip("no puzzle input"),
+        Err(e) => panic!("{e}"),
+    };
+    let Some(answer) = solution::solve_part::<S>(&S::parse(&input), part) else {
+        return skip("not solved");
+    };
+    assert_eq!(answer, expected, "{year} day {day} part {part}");
+}
+
+/// Generate a `#[test]` per part, checking answers on puzzle input against
+/// the answers file, see [`check`]:
+///
+/// ```ignore
+/// utils::answer_tests!(Puzzle; 2023, 17);
+/// ```
+#[macro_export]
+macro_rules! answer_tests {
+    ($solution:ty; $year:expr, $day:expr) => {
+        mod answers {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #[test]
+            fn part1() {
+                $crate::answers::check::<$solution>($year, $day, 1);
+            }
+
+            #[test]
+            fn part2() {
+                $crate::answers::check::<$solution>($year, $day, 2);
+            }
+        }
+    };
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    /// line number and content of a line which is not `<year> d<day> part<part>: <answer>`
+    Invalid(usize, String),
+    Io(PathBuf, std::io::Error),
+}
+
+impl std::fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Invalid(line, content) => write!(
+                f,
+                "invalid answer line {line} '{content}', expected '<year> d<day> part<part>: <answer>'"
+            ),
+            AnswersError::Io(path, e) => write!(f, "unable to read {}: {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AnswersError::Invalid(..) => None,
+            AnswersError::Io(_, e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse(
+            "# comment
+2023 d17 part1: 928
+
+2023 d17 part2:1104
+2025 d1 part1 : 1036  ",
+        )
+        .unwrap();
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers.get(2023, 17, 1), Some("928"));
+        assert_eq!(answers.get(2023, 17, 2), Some("1104"));
+        assert_eq!(answers.get(2025, 1, 1), Some("1036"));
+        assert_eq!(answers.get(2025, 1, 2), None);
+    }
+
+    #[test]
+    fn invalid() {
+        for line in [
+            "2023 d17 928",
+            "2023 17 part1: 928",
+            "2023 d17 part1 x: 928",
+        ] {
+            match Answers::parse(line) {
+                Err(AnswersError::Invalid(1, l)) => assert_eq!(l, line),
+                r => panic!("unexpected {r:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn missing_file() {
+        let answers = Answers::load(Path::new("/no/such/answers")).unwrap();
+        assert!(answers.is_empty());
+    }
+}
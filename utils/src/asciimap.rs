@@ -8,13 +8,33 @@ pub enum Rotation {
     R270,
 }
 
+/// byte to char conversion table, allow returning references to chars
+/// while map is stored as bytes
+static CHARS: [char; 256] = {
+    let mut table = ['\0'; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = i as u8 as char;
+        i += 1;
+    }
+    table
+};
+
+#[derive(Clone)]
 pub struct AsciiMap {
-    map: Vec<char>,
+    /// map cells, one byte per cell
+    map: Vec<u8>,
     width: usize,
     height: usize,
     rotation: Rotation,
 }
 
+impl Default for AsciiMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl AsciiMap {
     pub fn new() -> Self {
         Self {
@@ -27,29 +47,20 @@ impl AsciiMap {
 
     pub fn filled(w: usize, h: usize, c: char) -> Self {
         Self {
-            map: vec![c; w * h],
+            map: vec![Self::byte(c); w * h],
             width: w,
             height: h,
             rotation: Rotation::R0,
         }
     }
 
-    pub fn clone(&self) -> Self {
-        Self {
-            map: self.map.clone(),
-            width: self.width,
-            height: self.height,
-            rotation: self.rotation,
-        }
-    }
-
     /// Build a map of same side filled with provided char
     pub fn clone_and_fill(&self, c: char) -> Self {
         let w = self.width;
         let h = self.height;
         let rotation = self.rotation;
         Self {
-            map: vec![c; w * h],
+            map: vec![Self::byte(c); w * h],
             width: w,
             height: h,
             rotation,
@@ -57,37 +68,59 @@ impl AsciiMap {
     }
 
     pub fn from_multi_lines(lines: impl AsRef<str>) -> Self {
-        let lines: &str = lines.as_ref();
-        let mut _self = AsciiMap::new();
+        Self::from_bytes(lines.as_ref().as_bytes())
+    }
 
-        // find longest line
-        let lines: Vec<&str> = lines.split('\n').map(|s| s).collect();
-        _self.width = lines.iter().map(|s| s.len()).max().unwrap();
+    /// Fill in map from a multiline string, padding with default char if
+    /// map is not square
+    pub fn from_multi_lines_or(lines: impl AsRef<str>, default: char) -> Self {
+        let lines = lines.as_ref();
+        let mut _self = Self::from_bytes_or(lines.as_bytes(), default);
+        // a trailing new line starts a last line, made only of padding
+        if lines.ends_with('\n') {
+            _self.push_bytes_or(b"", Self::byte(default));
+        }
+        _self
+    }
 
-        for line in lines {
+    /// Build map from raw input bytes, all lines should have the same length
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut _self = AsciiMap::new();
+        _self.width = Self::lines(bytes).map(|l| l.len()).max().unwrap();
+
+        for line in Self::lines(bytes) {
             assert!(line.len() == _self.width);
-            _self.push_or(line, ' ');
+            _self.push_bytes_or(line, b' ');
         }
         _self
     }
 
-    /// Fill in map from a multiline string, padding with default char if
+    /// Build map from raw input bytes, padding with default char if
     /// map is not square
-    pub fn from_multi_lines_or(lines: impl AsRef<str>, default: char) -> Self {
-        let lines: &str = lines.as_ref();
+    pub fn from_bytes_or(bytes: &[u8], default: char) -> Self {
         let mut _self = AsciiMap::new();
+        _self.width = Self::lines(bytes).map(|l| l.len()).max().unwrap();
 
-        // find longest line
-        let lines: Vec<&str> = lines.split('\n').map(|s| s).collect();
-        // set map width
-        _self.width = lines.iter().map(|s| s.len()).max().unwrap();
-
-        for line in lines {
-            _self.push_or(line, default);
+        for line in Self::lines(bytes) {
+            _self.push_bytes_or(line, Self::byte(default));
         }
         _self
     }
 
+    /// split bytes into lines, a trailing new line ends the last line
+    fn lines(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
+        let bytes = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+        bytes
+            .split(|&b| b == b'\n')
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+    }
+
+    /// convert char to the byte stored in map
+    fn byte(c: char) -> u8 {
+        assert!(c.is_ascii(), "non-ASCII char {c:?}");
+        c as u8
+    }
+
     pub fn width(&self) -> usize {
         self.width
     }
@@ -127,14 +160,19 @@ impl AsciiMap {
 
     // push line to map padding line with default char if some are missing
     pub fn push_or(&mut self, line: &str, default: char) {
+        self.push_bytes_or(line.as_bytes(), Self::byte(default));
+    }
+
+    fn push_bytes_or(&mut self, line: &[u8], default: u8) {
         // width should be set
         assert!(self.width > 0);
         // line length should not exceed map width
         assert!(line.len() <= self.width);
+        assert!(line.is_ascii());
 
-        for idx in 0..self.width {
-            self.map.push(line.chars().nth(idx).unwrap_or(default));
-        }
+        self.map.extend_from_slice(line);
+        self.map
+            .extend(std::iter::repeat_n(default, self.width - line.len()));
 
         self.height += 1;
     }
@@ -144,15 +182,20 @@ impl AsciiMap {
             self.width = line.len();
         }
         assert_eq!(self.width, line.len());
+        assert!(line.is_ascii());
 
-        for c in line.chars() {
-            self.map.push(c);
-        }
+        self.map.extend_from_slice(line.as_bytes());
 
         self.height += 1;
     }
 
+    /// Show map with column and row numbers, at debug level
     pub fn print(&self) {
+        crate::debug!("\n{}", self.ruled());
+    }
+
+    /// map with column numbers above and row numbers on the left
+    fn ruled(&self) -> String {
         let mut sd = String::new();
         let mut su = String::new();
 
@@ -165,19 +208,20 @@ impl AsciiMap {
             su.push(std::char::from_digit(i % 10, 10).unwrap());
         }
 
+        let mut out = String::new();
         if width > 10 {
-            eprintln!("   {sd}");
+            out.push_str(&format!("   {sd}\n"));
         }
-        eprintln!("   {su}");
-        eprintln!("");
+        out.push_str(&format!("   {su}\n\n"));
         for y in 0..self.height() {
             let mut s = String::new();
             for x in 0..width {
                 let c = self.get(x, y).unwrap();
                 s.push(*c);
             }
-            eprintln!("{y:2} {s}");
+            out.push_str(&format!("{y:2} {s}\n"));
         }
+        out
     }
 
     fn index_to_xy(&self, k: usize) -> (usize, usize) {
@@ -201,7 +245,7 @@ impl AsciiMap {
         }
         let (x, y) = self.rotate(x, y);
         let k = self.xy_to_index(x, y);
-        self.map[k] = c;
+        self.map[k] = Self::byte(c);
         Some(())
     }
 
@@ -218,7 +262,7 @@ impl AsciiMap {
         }
         let (x, y) = self.rotate(x, y);
         let k = self.xy_to_index(x, y);
-        self.map.get(k)
+        self.map.get(k).map(|&b| &CHARS[b as usize])
     }
 
     pub fn swap(&mut self, xa: usize, ya: usize, xb: usize, yb: usize) {
@@ -230,12 +274,9 @@ impl AsciiMap {
 
     // return position of first occurence of char, None if not found
     pub fn find(&self, sc: char) -> Option<(usize, usize)> {
-        for (k, &mc) in self.map.iter().enumerate() {
-            if mc == sc {
-                return Some(self.index_to_xy(k));
-            }
-        }
-        None
+        let sc = Self::byte(sc);
+        let k = self.map.iter().position(|&mc| mc == sc)?;
+        Some(self.index_to_xy(k))
     }
 
     pub fn ifind(&self, sc: char) -> Option<(isize, isize)> {
@@ -245,22 +286,31 @@ impl AsciiMap {
 
     // count occurences of char in map
     pub fn count(&self, sc: char) -> usize {
-        self.map.iter().filter(|c| sc == **c).count()
+        let sc = Self::byte(sc);
+        self.map.iter().filter(|&&c| sc == c).count()
     }
 
-    // return map line as a slice
-    pub fn line(&self, y: usize) -> Option<&[char]> {
+    // return map line as text (map rotation is not applied), cells are
+    // stored as ASCII bytes so lines are no longer available as `&[char]`
+    pub fn line(&self, y: usize) -> Option<&str> {
+        // only ASCII bytes are pushed to map
+        self.line_bytes(y)
+            .map(|line| std::str::from_utf8(line).unwrap())
+    }
+
+    // return map line as a slice of bytes (map rotation is not applied)
+    pub fn line_bytes(&self, y: usize) -> Option<&[u8]> {
         let start = y * self.width;
-        Some(&self.map[start..start + self.width])
+        self.map.get(start..start + self.width)
     }
 
     /// iterate through elements of map return element and position
     pub fn iter(&self) -> impl Iterator<Item = (char, (usize, usize))> + use<'_> {
-        self.map.iter().enumerate().map(|(index, &c)| {
+        self.map.iter().enumerate().map(|(index, &b)| {
             // convert index to 2D position
             let xy = self.index_to_xy(index);
             // return a (char, position) tuple
-            (c, xy)
+            (b as char, xy)
         })
     }
 
@@ -273,17 +323,17 @@ impl AsciiMap {
         // NOTE: quick and dirty PPM implementation
 
         // PPM pixels in ASCII RGB
-        write!(f, "P3\n")?;
+        writeln!(f, "P3")?;
         // image size
-        write!(f, "{} {}\n", self.width, self.height)?;
+        writeln!(f, "{} {}", self.width, self.height)?;
         // 255 maximum value for colors
-        write!(f, "255\n")?;
+        writeln!(f, "255")?;
 
         for y in 0..self.height {
             for x in 0..self.width {
                 let &c = self.get(x, y).unwrap();
                 let (r, g, b) = convert(c);
-                write!(f, "{} {} {}\n", r, g, b)?;
+                writeln!(f, "{} {} {}", r, g, b)?;
             }
         }
 
@@ -294,7 +344,7 @@ impl AsciiMap {
 impl std::fmt::Debug for AsciiMap {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AsciiMap")
-            .field("map", &self.map)
+            .field("map", &String::from_utf8_lossy(&self.map))
             .field("width", &self.width)
             .field("height", &self.height)
             .field("rotation", &self.rotation)
@@ -310,3 +360,49 @@ impl PartialEq for AsciiMap {
             && self.rotation == other.rotation
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn from_bytes_not_ascii() {
+        AsciiMap::from_bytes("#é\n.#.\n".as_bytes());
+    }
+
+    #[test]
+    fn from_bytes() {
+        let map = AsciiMap::from_bytes(b"#.#\n..#\n");
+        assert_eq!(map.size(), (3, 2));
+        assert_eq!(map.get(0, 0), Some(&'#'));
+        assert_eq!(map.get(1, 1), Some(&'.'));
+        assert_eq!(map.get(3, 0), None);
+        assert_eq!(map.line(1), Some("..#"));
+        assert_eq!(map.line_bytes(1), Some(&b"..#"[..]));
+        assert_eq!(map.line(2), None);
+        assert_eq!(map.count('#'), 3);
+    }
+
+    #[test]
+    fn crlf() {
+        let map = AsciiMap::from_multi_lines("ab\r\ncd\r\n");
+        assert_eq!(map.size(), (2, 2));
+        assert_eq!(map.find('d'), Some((1, 1)));
+    }
+
+    #[test]
+    fn padded() {
+        let mut map = AsciiMap::from_bytes_or(b"abc\na", '_');
+        assert_eq!(map.get(2, 1), Some(&'_'));
+        map.set(1, 1, 'x');
+        assert_eq!(map.iter().filter(|(c, _)| *c == 'x').count(), 1);
+
+        // trailing new line adds a padding line, as it always did
+        let map = AsciiMap::from_multi_lines_or("abc\na\n", '_');
+        assert_eq!(map.size(), (3, 3));
+        assert_eq!(map.line(2), Some("___"));
+        // while raw bytes end their last line with it
+        assert_eq!(AsciiMap::from_bytes_or(b"abc\na\n", '_').size(), (3, 2));
+    }
+}
@@ -0,0 +1,171 @@
+use std::str::FromStr;
+
+/// A group of adjacent columns of a text block, bounded by all-blank columns
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnGroup {
+    /// column of text block where group starts
+    x: usize,
+    /// group rows, padded with spaces to group width
+    rows: Vec<String>,
+    /// trimmed footer row (operator row of worksheet-style inputs)
+    footer: Option<String>,
+}
+
+impl ColumnGroup {
+    /// column of text block where group starts
+    pub fn x(&self) -> usize {
+        self.x
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows.first().map(|row| row.len()).unwrap_or(0)
+    }
+
+    /// rows of group, footer excluded
+    pub fn rows(&self) -> &[String] {
+        &self.rows
+    }
+
+    pub fn footer(&self) -> Option<&str> {
+        self.footer.as_deref()
+    }
+
+    /// read one number per row, rows which are blank or do not parse are skipped
+    pub fn numbers_by_row<T: FromStr>(&self) -> Vec<T> {
+        self.rows
+            .iter()
+            .filter_map(|row| row.trim().parse().ok())
+            .collect()
+    }
+
+    /// read one number per column (digits written top to bottom), from left to right,
+    /// columns which are blank or do not parse are skipped
+    pub fn numbers_by_column<T: FromStr>(&self) -> Vec<T> {
+        (0..self.width())
+            .filter_map(|x| {
+                let s: String = self
+                    .rows
+                    .iter()
+                    .map(|row| row.as_bytes()[x] as char)
+                    .collect();
+                s.trim().parse().ok()
+            })
+            .collect()
+    }
+}
+
+/// Split an ASCII text block into groups of columns separated by all-blank
+/// columns, panics if block is not ASCII
+pub fn column_groups(input: &str) -> Vec<ColumnGroup> {
+    split_groups(input, false)
+}
+
+/// Split an ASCII text block into groups of columns separated by all-blank
+/// columns, last line of block is the footer of each group
+pub fn column_groups_with_footer(input: &str) -> Vec<ColumnGroup> {
+    split_groups(input, true)
+}
+
+fn split_groups(input: &str, with_footer: bool) -> Vec<ColumnGroup> {
+    // columns are bytes, they would not line up with multi-byte chars
+    assert!(input.is_ascii(), "text block is not ASCII");
+
+    // leading and trailing new lines are not part of block, but spaces are
+    let lines: Vec<&str> = input
+        .trim_matches('\n')
+        .split('\n')
+        .map(|line| line.trim_end_matches('\r'))
+        .collect();
+
+    // pad each line to block width so every column exists on every line
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let lines: Vec<String> = lines.iter().map(|line| format!("{line:width$}")).collect();
+
+    let blank = |x: usize| lines.iter().all(|line| line.as_bytes()[x] == b' ');
+
+    let mut groups = vec![];
+    let mut x = 0;
+    while x < width {
+        // skip separating columns
+        if blank(x) {
+            x += 1;
+            continue;
+        }
+
+        // march right until next blank column or end of block
+        let x0 = x;
+        while x < width && !blank(x) {
+            x += 1;
+        }
+
+        let mut rows: Vec<String> = lines.iter().map(|line| line[x0..x].to_string()).collect();
+        let footer = if with_footer {
+            rows.pop().map(|row| row.trim().to_string())
+        } else {
+            None
+        };
+
+        groups.push(ColumnGroup {
+            x: x0,
+            rows,
+            footer,
+        });
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKSHEET: &str = "123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   + ";
+
+    #[test]
+    fn groups() {
+        let groups = column_groups_with_footer(WORKSHEET);
+        assert_eq!(groups.len(), 4);
+        assert_eq!(
+            groups.iter().map(|g| g.x()).collect::<Vec<_>>(),
+            vec![0, 4, 8, 12]
+        );
+        assert_eq!(
+            groups
+                .iter()
+                .map(|g| g.footer().unwrap())
+                .collect::<Vec<_>>(),
+            vec!["*", "+", "*", "+"]
+        );
+    }
+
+    #[test]
+    fn by_row() {
+        let groups = column_groups_with_footer(WORKSHEET);
+        assert_eq!(groups[0].numbers_by_row::<u32>(), vec![123, 45, 6]);
+        assert_eq!(groups[3].numbers_by_row::<u32>(), vec![64, 23, 314]);
+    }
+
+    #[test]
+    fn by_column() {
+        let groups = column_groups_with_footer(WORKSHEET);
+        assert_eq!(groups[0].numbers_by_column::<u32>(), vec![1, 24, 356]);
+        assert_eq!(groups[3].numbers_by_column::<u32>(), vec![623, 431, 4]);
+    }
+
+    #[test]
+    fn no_footer() {
+        let groups = column_groups("1 2\n3 4\n");
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[1].numbers_by_row::<u8>(), vec![2, 4]);
+        assert_eq!(groups[1].footer(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "not ASCII")]
+    fn not_ascii() {
+        column_groups("1 é\n2 3\n");
+    }
+}
@@ -0,0 +1,177 @@
+/// Separator between header and input of an example file
+pub const HEADER_END: &str = "---";
+
+/// An example input along with its expected answers.
+///
+/// Example files start with a small header giving expected answers per part,
+/// ended by a `---` line, followed by the example input:
+///
+/// ```text
+/// part1: 102
+/// part2: 94
+/// ---
+/// 2413432311323
+/// 3215453535623
+/// ```
+///
+/// A file without header is an example with no known answers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    /// (part, expected answer) pairs, in header order
+    pub answers: Vec<(String, String)>,
+    pub input: String,
+}
+
+impl Example {
+    pub fn parse(text: &str) -> Self {
+        if let Some((header, input)) = Self::split_header(text) {
+            let answers = header
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .map(|(part, answer)| (part.trim().to_string(), answer.trim().to_string()))
+                .collect();
+            Self {
+                answers,
+                input: Self::strip_last_newline(input).to_string(),
+            }
+        } else {
+            Self {
+                answers: vec![],
+                input: Self::strip_last_newline(text).to_string(),
+            }
+        }
+    }
+
+    /// return expected answer for part (e.g. "part1"), if known
+    pub fn answer(&self, part: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|(p, _)| p == part)
+            .map(|(_, answer)| answer.as_str())
+    }
+
+    /// split text into (header, input) if it starts with a header
+    fn split_header(text: &str) -> Option<(&str, &str)> {
+        let mut offset = 0;
+        for line in text.split_inclusive('\n') {
+            let l = line.trim_end();
+            if l == HEADER_END {
+                return Some((&text[..offset], &text[offset + line.len()..]));
+            }
+            // every header line is a `part: answer` pair
+            let (part, _) = l.split_once(':')?;
+            if !part.trim().starts_with("part") {
+                return None;
+            }
+            offset += line.len();
+        }
+        None
+    }
+
+    /// line terminator of last line belongs to file, not to example
+    fn strip_last_newline(s: &str) -> &str {
+        let s = s.strip_suffix('\n').unwrap_or(s);
+        s.strip_suffix('\r').unwrap_or(s)
+    }
+}
+
+/// Generate a `#[test]` per example file per part, checking solver answer
+/// against the answer given in the example header.
+///
+/// Example files are looked up in the crate directory by name, each example
+/// gets its own module with a test per part. Parts are either given by a
+/// [`Solution`](crate::solution::Solution) type:
+///
+/// ```ignore
+/// utils::example_tests!(Puzzle; example1, example2);
+/// ```
+///
+/// or by a solver per part, taking the example input as `&str`:
+///
+/// ```ignore
+/// utils::example_tests! {
+///     part1 => |input: &str| solve(input, 6),
+///     part2 => |input: &str| solve(input, 12);
+///     example1, example2
+/// }
+/// ```
+///
+/// Parts without an expected answer in an example header are skipped, with
+/// a note on stderr.
+#[macro_export]
+macro_rules! example_tests {
+    ($($part:ident => $solver:expr),+ $(,)? ; $($name:ident),+ $(,)?) => {
+        $crate::example_tests!(@examples [$($part => $solver),+] $($name)+);
+    };
+    ($solution:ty; $($name:ident),+ $(,)?) => {
+        $crate::example_tests! {
+            part1 => |input: &str| {
+                use $crate::solution::Solution;
+                <$solution>::part1(&<$solution>::parse(input)).to_string()
+            },
+            part2 => |input: &str| {
+                use $crate::solution::Solution;
+                <$solution>::part2(&<$solution>::parse(input)).to_string()
+            };
+            $($name),+
+        }
+    };
+    (@examples $parts:tt $($name:ident)+) => {
+        $( $crate::example_tests!(@example $name $parts); )+
+    };
+    (@example $name:ident [$($part:ident => $solver:expr),+]) => {
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                #[test]
+                fn $part() {
+                    let example = $crate::examples::Example::parse(include_str!(concat!(
+                        env!("CARGO_MANIFEST_DIR"),
+                        "/",
+                        stringify!($name)
+                    )));
+                    let Some(expected) = example.answer(stringify!($part)) else {
+                        eprintln!(
+                            "{} {}: skipped, no expected answer",
+                            stringify!($name),
+                            stringify!($part)
+                        );
+                        return;
+                    };
+                    let answer = ($solver)(example.input.as_str());
+                    assert_eq!(format!("{}", answer), expected);
+                }
+            )+
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header() {
+        let example = Example::parse("part1: 102\npart2: 94\n---\n123\n456\n");
+        assert_eq!(example.answer("part1"), Some("102"));
+        assert_eq!(example.answer("part2"), Some("94"));
+        assert_eq!(example.answer("part3"), None);
+        assert_eq!(example.input, "123\n456");
+    }
+
+    #[test]
+    fn no_header() {
+        let example = Example::parse("123\n---\n456\n");
+        assert!(example.answers.is_empty());
+        assert_eq!(example.input, "123\n---\n456");
+    }
+
+    #[test]
+    fn significant_spaces() {
+        let example = Example::parse("part2: 8\n---\n 1 \n 2 \n\n");
+        assert_eq!(example.answer("part1"), None);
+        assert_eq!(example.input, " 1 \n 2 \n");
+    }
+}
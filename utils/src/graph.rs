@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+
+/// Interned node identifier, nodes are numbered in order of first appearance
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge<L> {
+    pub to: NodeId,
+    pub label: L,
+}
+
+/// A directed graph with named nodes and labelled edges
+#[derive(Debug, Clone)]
+pub struct Graph<L = usize> {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    edges: Vec<Vec<Edge<L>>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// line number (starting at 1) of faulty line
+    pub line: usize,
+    pub reason: &'static str,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl<L> Default for Graph<L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L> Graph<L> {
+    pub fn new() -> Self {
+        Self {
+            names: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+        }
+    }
+
+    /// return id of named node, creating node if it does not exist yet
+    pub fn node(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.edges.push(vec![]);
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    /// number of nodes
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(|e| e.len()).sum()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        0..self.len()
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, label: L) {
+        self.edges[from].push(Edge { to, label });
+    }
+
+    /// outgoing edges of node, in insertion order
+    pub fn edges(&self, id: NodeId) -> &[Edge<L>] {
+        &self.edges[id]
+    }
+
+    pub fn successors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges[id].iter().map(|e| e.to)
+    }
+
+    pub fn has_edge(&self, from: NodeId, to: NodeId) -> bool {
+        self.successors(from).any(|id| id == to)
+    }
+}
+
+impl<L: PartialEq> Graph<L> {
+    /// follow edge with given label from node
+    pub fn successor(&self, id: NodeId, label: &L) -> Option<NodeId> {
+        self.edges[id]
+            .iter()
+            .find(|e| e.label == *label)
+            .map(|e| e.to)
+    }
+}
+
+impl Graph<usize> {
+    /// Parse an adjacency list, one node per line followed by its successors:
+    ///
+    /// ```text
+    /// AAA = (BBB, CCC)
+    /// a: b c d
+    /// x -> y, z
+    /// ```
+    ///
+    /// each edge is labelled with the position of the successor in its list
+    pub fn from_adjacency_list(input: &str) -> Result<Self, ParseError> {
+        let mut graph = Self::new();
+        for (k, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let err = |reason| ParseError {
+                line: k + 1,
+                reason,
+            };
+
+            // node name is separated from successors by the earliest separator
+            let (at, sep) = ["->", "=", ":"]
+                .iter()
+                .filter_map(|sep| line.find(sep).map(|at| (at, sep)))
+                .min()
+                .ok_or(err("missing separator"))?;
+            let (name, successors) = (&line[..at], &line[at + sep.len()..]);
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(err("missing node name"));
+            }
+
+            let from = graph.node(name);
+            let successors = successors
+                .trim()
+                .trim_start_matches(['(', '[', '{'])
+                .trim_end_matches([')', ']', '}']);
+            for (label, to) in successors
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|s| !s.is_empty())
+                .enumerate()
+            {
+                let to = graph.node(to);
+                graph.add_edge(from, to, label);
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Parse an edge list, one `from<sep>to` edge per line (e.g. `47|53`),
+    /// each edge is labelled with its index in the list
+    pub fn from_edge_list(input: &str, sep: char) -> Result<Self, ParseError> {
+        let mut graph = Self::new();
+        let mut label = 0;
+        for (k, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (a, b) = line.split_once(sep).ok_or(ParseError {
+                line: k + 1,
+                reason: "missing separator",
+            })?;
+            let a = graph.node(a.trim());
+            let b = graph.node(b.trim());
+            graph.add_edge(a, b, label);
+            label += 1;
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adjacency_list() {
+        let input = "AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)";
+        let graph = Graph::from_adjacency_list(input).unwrap();
+        assert_eq!(graph.len(), 7);
+        assert_eq!(graph.edge_count(), 6);
+
+        let aaa = graph.id("AAA").unwrap();
+        let ccc = graph.successor(aaa, &1).unwrap();
+        assert_eq!(graph.name(ccc), "CCC");
+        assert_eq!(graph.name(graph.successor(ccc, &0).unwrap()), "ZZZ");
+        assert_eq!(graph.successor(ccc, &2), None);
+    }
+
+    #[test]
+    fn adjacency_list_formats() {
+        let graph = Graph::from_adjacency_list("a: b c\nb -> c, a\n").unwrap();
+        let (a, b, c) = (0, 1, 2);
+        assert_eq!(graph.successors(a).collect::<Vec<_>>(), vec![b, c]);
+        assert_eq!(graph.successors(b).collect::<Vec<_>>(), vec![c, a]);
+        assert_eq!(graph.successors(c).count(), 0);
+
+        // separator found first in line wins, whatever its kind
+        let graph = Graph::from_adjacency_list("a: b=c d->e").unwrap();
+        assert_eq!(graph.name(0), "a");
+        assert_eq!(graph.name(1), "b=c");
+        assert_eq!(graph.name(2), "d->e");
+
+        let e = Graph::from_adjacency_list("a b c").unwrap_err();
+        assert_eq!(e.line, 1);
+    }
+
+    #[test]
+    fn edge_list() {
+        let graph = Graph::from_edge_list("47|53\n97|13\n97|61\n\n", '|').unwrap();
+        let n97 = graph.id("97").unwrap();
+        assert_eq!(graph.edges(n97).len(), 2);
+        assert!(graph.has_edge(n97, graph.id("61").unwrap()));
+        assert!(!graph.has_edge(n97, graph.id("53").unwrap()));
+        assert_eq!(graph.edges(n97)[1].label, 2);
+    }
+}
@@ -1,5 +1,276 @@
+use std::io::{BufRead, BufReader, Read};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
+use crate::examples::Example;
+
+/// Environment variable naming a directory of cached inputs,
+/// laid out as `<dir>/<year>/d<day>/input`
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Environment variable naming the input file of a single run when none is
+/// given on the command line, `-` reads input from stdin. [`Loader`] does not
+/// read it, so that it does not leak to every day a process loads.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Example files looked up in day directory when no input is found
+pub const EXAMPLE_NAMES: [&str; 2] = ["example", "example1"];
+
+/// Where an input was found
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+    Example(PathBuf),
+}
+
+impl Source {
+    pub fn is_example(&self) -> bool {
+        matches!(self, Source::Example(_))
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let mut input = String::new();
+        match self {
+            Source::Stdin => std::io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| InputError::Io(PathBuf::from("-"), e))?,
+            Source::File(path) | Source::Example(path) => std::fs::File::open(path)
+                .and_then(|mut f| f.read_to_string(&mut input))
+                .map_err(|e| InputError::Io(path.clone(), e))?,
+        };
+        if self.is_example() {
+            // drop expected answers header
+            input = Example::parse(&input).input;
+        }
+        Ok(input)
+    }
+
+    /// Read input as raw bytes, files are memory-mapped when `mmap` feature is enabled
+    pub fn bytes(&self) -> Result<Bytes, InputError> {
+        match self {
+            #[cfg(feature = "mmap")]
+            Source::File(path) => {
+                let file =
+                    std::fs::File::open(path).map_err(|e| InputError::Io(path.clone(), e))?;
+                // SAFETY: input files are not expected to be modified while solving
+                let map = unsafe { memmap2::Mmap::map(&file) }
+                    .map_err(|e| InputError::Io(path.clone(), e))?;
+                Ok(Bytes::Mapped(map))
+            }
+            Source::Stdin | Source::Example(_) => Ok(Bytes::Owned(self.read()?.into_bytes())),
+            #[cfg(not(feature = "mmap"))]
+            Source::File(path) => std::fs::read(path)
+                .map(Bytes::Owned)
+                .map_err(|e| InputError::Io(path.clone(), e)),
+        }
+    }
+
+    /// Open input to stream it, without holding it whole in memory
+    pub fn reader(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Source::Stdin => Ok(Box::new(BufReader::new(std::io::stdin()))),
+            Source::File(path) => std::fs::File::open(path)
+                .map(|f| Box::new(BufReader::new(f)) as Box<dyn BufRead>)
+                .map_err(|e| InputError::Io(path.clone(), e)),
+            Source::Example(_) => Ok(Box::new(std::io::Cursor::new(self.read()?))),
+        }
+    }
+}
+
+/// Raw input bytes, either held in memory or memory-mapped
+pub enum Bytes {
+    Owned(Vec<u8>),
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+}
+
+impl Bytes {
+    pub fn as_str(&self) -> Result<&str, std::str::Utf8Error> {
+        std::str::from_utf8(self)
+    }
+}
+
+impl Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Bytes::Owned(v) => v,
+            #[cfg(feature = "mmap")]
+            Bytes::Mapped(m) => m,
+        }
+    }
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Example(path) => write!(f, "{} (example)", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// no input found for day, with the list of searched paths
+    NotFound {
+        year: u32,
+        day: u32,
+        searched: Vec<PathBuf>,
+    },
+    /// input exists but could not be read
+    Io(PathBuf, std::io::Error),
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound {
+                year,
+                day,
+                searched,
+            } => {
+                write!(f, "no input found for {year} day {day}, searched:")?;
+                for path in searched {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                write!(
+                    f,
+                    "\nset {INPUT_DIR_VAR} to a directory of inputs or give an input path (- for stdin)"
+                )
+            }
+            InputError::Io(path, e) => write!(f, "unable to read {}: {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io(_, e) => Some(e),
+        }
+    }
+}
+
+/// Return directory of a day crate in this repository
+pub fn day_dir(year: u32, day: u32) -> PathBuf {
+    // utils lives at the root of the repository, next to years directories
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(format!("{year}"))
+        .join(format!("d{day}"))
+}
+
+/// Find input of a day, see [`Loader`] for lookup order
+#[derive(Debug, Clone)]
+pub struct Loader {
+    year: u32,
+    day: u32,
+    path: Option<PathBuf>,
+    examples: bool,
+}
+
+impl Loader {
+    pub fn new(year: u32, day: u32) -> Self {
+        Self {
+            year,
+            day,
+            path: None,
+            examples: true,
+        }
+    }
+
+    /// use this file instead of searching for input (`-` for stdin)
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// allow (default) or forbid falling back to example files
+    pub fn examples(mut self, examples: bool) -> Self {
+        self.examples = examples;
+        self
+    }
+
+    /// Find input, looking in order for:
+    /// - the file given to [`Loader::path`] (`-` for stdin)
+    /// - `input` in the cache directory given in `AOC_INPUT_DIR`
+    /// - `input` in the day crate directory
+    /// - example files in the day crate directory
+    pub fn locate(&self) -> Result<Source, InputError> {
+        if let Some(path) = self.path.clone() {
+            return if path.as_os_str() == "-" {
+                Ok(Source::Stdin)
+            } else {
+                Ok(Source::File(path))
+            };
+        }
+
+        let mut searched = vec![];
+        let dir = day_dir(self.year, self.day);
+
+        // real inputs
+        let mut candidates = vec![];
+        if let Some(cache) = std::env::var_os(INPUT_DIR_VAR) {
+            candidates.push(
+                Path::new(&cache)
+                    .join(format!("{}", self.year))
+                    .join(format!("d{}", self.day))
+                    .join("input"),
+            );
+        }
+        candidates.push(dir.join("input"));
+        for path in candidates {
+            if path.is_file() {
+                return Ok(Source::File(path));
+            }
+            searched.push(path);
+        }
+
+        // examples
+        if self.examples {
+            for name in EXAMPLE_NAMES {
+                let path = dir.join(name);
+                if path.is_file() {
+                    return Ok(Source::Example(path));
+                }
+                searched.push(path);
+            }
+        }
+
+        Err(InputError::NotFound {
+            year: self.year,
+            day: self.day,
+            searched,
+        })
+    }
+
+    pub fn load(&self) -> Result<String, InputError> {
+        self.locate()?.read()
+    }
+
+    /// load input as raw bytes, see [`Source::bytes`]
+    pub fn load_bytes(&self) -> Result<Bytes, InputError> {
+        self.locate()?.bytes()
+    }
+
+    /// open input for streaming, see [`Source::reader`]
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        self.locate()?.reader()
+    }
+}
+
+/// Load input of a day, falling back to examples if no input is found
+pub fn load(year: u32, day: u32) -> Result<String, InputError> {
+    Loader::new(year, day).load()
+}
+
 pub fn from_separated_values<T: FromStr>(input: &str) -> Vec<Vec<T>> {
     input
         .split('\n')
@@ -12,6 +283,37 @@ pub fn from_separated_values<T: FromStr>(input: &str) -> Vec<Vec<T>> {
         .collect()
 }
 
+/// Integers written in text, as digit runs along with their sign: `-` is a
+/// sign unless it follows a digit or letter (`v=-3` holds -3, `x-1` holds 1)
+pub fn integer_tokens(input: &str) -> impl Iterator<Item = &str> + '_ {
+    let bytes = input.as_bytes();
+    let mut idx = 0;
+    std::iter::from_fn(move || {
+        while idx < bytes.len() && !bytes[idx].is_ascii_digit() {
+            idx += 1;
+        }
+        if idx == bytes.len() {
+            return None;
+        }
+        let mut start = idx;
+        if start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric())
+        {
+            start -= 1;
+        }
+        while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+            idx += 1;
+        }
+        Some(&input[start..idx])
+    })
+}
+
+/// Integers written in text which fit in `T`, see [`integer_tokens`]
+pub fn integers<T: FromStr>(input: &str) -> impl Iterator<Item = T> + '_ {
+    integer_tokens(input).filter_map(|token| token.parse().ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,4 +342,67 @@ mod tests {
             vec![vec![1, 2], vec![3, 4], vec![5, 6]]
         );
     }
+
+    #[test]
+    fn integer_scan() {
+        let input = "p=0,4 v=-3,3\nx-1 -12-5 300";
+        assert_eq!(
+            integer_tokens(input).collect::<Vec<_>>(),
+            vec!["0", "4", "-3", "3", "1", "-12", "5", "300"]
+        );
+        assert_eq!(
+            integers::<i8>(input).collect::<Vec<_>>(),
+            vec![0, 4, -3, 3, 1, -12, 5]
+        );
+    }
+
+    #[test]
+    fn day_dirs() {
+        let dir = day_dir(2025, 6);
+        assert!(dir.ends_with("2025/d6"));
+        assert!(dir.join("Cargo.toml").is_file());
+    }
+
+    #[test]
+    fn explicit_path() {
+        let loader = Loader::new(2025, 6).path("/some/file");
+        assert_eq!(
+            loader.locate().unwrap(),
+            Source::File(PathBuf::from("/some/file"))
+        );
+        let loader = Loader::new(2025, 6).path("-");
+        assert_eq!(loader.locate().unwrap(), Source::Stdin);
+    }
+
+    #[test]
+    fn not_found() {
+        let e = Loader::new(1999, 42).examples(false).load().unwrap_err();
+        match e {
+            InputError::NotFound { searched, .. } => {
+                assert!(searched.last().unwrap().ends_with("1999/d42/input"))
+            }
+            e => panic!("unexpected error {e}"),
+        }
+    }
+
+    #[test]
+    fn bytes_and_stream() {
+        let path = std::env::temp_dir().join(format!("utils-inputs-{}", std::process::id()));
+        std::fs::write(&path, "12\n34\n").unwrap();
+        let source = Source::File(path.clone());
+
+        let bytes = source.bytes().unwrap();
+        assert_eq!(&*bytes, b"12\n34\n");
+        assert_eq!(bytes.as_str().unwrap(), "12\n34\n");
+
+        let lines: Vec<String> = source
+            .reader()
+            .unwrap()
+            .lines()
+            .map(|l| l.unwrap())
+            .collect();
+        assert_eq!(lines, vec!["12", "34"]);
+
+        std::fs::remove_file(path).unwrap();
+    }
 }
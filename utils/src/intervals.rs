@@ -0,0 +1,356 @@
+use std::ops::{Add, Range, RangeInclusive, Sub};
+
+/// Primitive integers, as bounds of [`IntervalSet`]
+pub trait Bound: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ONE: Self;
+
+    /// number of values in `first..=last`, for `first <= last`
+    fn count(first: Self, last: Self) -> u128;
+}
+
+macro_rules! bound {
+    ($($t:ty),*) => {
+        $(impl Bound for $t {
+            const ONE: Self = 1;
+
+            fn count(first: Self, last: Self) -> u128 {
+                (last.abs_diff(first) as u128).saturating_add(1)
+            }
+        })*
+    };
+}
+
+bound!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A set of integers stored as sorted, disjoint and non-adjacent intervals.
+///
+/// Intervals are inclusive internally, so that they may reach the largest
+/// value of `T`. Methods take and give half-open intervals (`a..b`), and
+/// `*_inclusive` methods inclusive ones (`a..=b`) instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    /// inclusive (first, last) intervals, sorted, with a gap between each
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T> IntervalSet<T>
+where
+    T: Bound,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// true if there is a gap between values `a` and `b`, `b` coming after
+    fn apart(a: T, b: T) -> bool {
+        // a + 1 can not overflow as a < b
+        a < b && a + T::ONE < b
+    }
+
+    /// add values of interval to set, merging it with any overlapping or adjacent interval
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.start < range.end {
+            self.insert_inclusive(range.start..=range.end - T::ONE);
+        }
+    }
+
+    pub fn insert_inclusive(&mut self, range: RangeInclusive<T>) {
+        let (first, last) = (*range.start(), *range.end());
+        if first > last {
+            return;
+        }
+        let lo = self.ranges.partition_point(|r| Self::apart(r.1, first));
+        let hi = self.ranges.partition_point(|r| !Self::apart(last, r.0));
+        let merged = if lo < hi {
+            (
+                first.min(self.ranges[lo].0),
+                last.max(self.ranges[hi - 1].1),
+            )
+        } else {
+            (first, last)
+        };
+        self.ranges.splice(lo..hi, [merged]);
+    }
+
+    /// remove values of interval from set, splitting intervals it falls into
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.start < range.end {
+            self.remove_inclusive(range.start..=range.end - T::ONE);
+        }
+    }
+
+    pub fn remove_inclusive(&mut self, range: RangeInclusive<T>) {
+        let (first, last) = (*range.start(), *range.end());
+        if first > last {
+            return;
+        }
+        let lo = self.ranges.partition_point(|r| r.1 < first);
+        let hi = self.ranges.partition_point(|r| r.0 <= last);
+        if lo >= hi {
+            return;
+        }
+        let (start, end) = (self.ranges[lo].0, self.ranges[hi - 1].1);
+        let left = (start < first).then(|| (start, first - T::ONE));
+        let right = (end > last).then(|| (last + T::ONE, end));
+        self.ranges.splice(lo..hi, left.into_iter().chain(right));
+    }
+
+    /// values in either set
+    pub fn union(&self, other: &Self) -> Self {
+        let mut ranges: Vec<(T, T)> = Vec::with_capacity(self.ranges.len() + other.ranges.len());
+        let (mut i, mut j) = (0, 0);
+        loop {
+            // next interval by start, from either set
+            let next = match (self.ranges.get(i), other.ranges.get(j)) {
+                (Some(&a), Some(&b)) if a.0 <= b.0 => {
+                    i += 1;
+                    a
+                }
+                (_, Some(&b)) => {
+                    j += 1;
+                    b
+                }
+                (Some(&a), None) => {
+                    i += 1;
+                    a
+                }
+                (None, None) => break,
+            };
+            match ranges.last_mut() {
+                Some(last) if !Self::apart(last.1, next.0) => last.1 = last.1.max(next.1),
+                _ => ranges.push(next),
+            }
+        }
+        Self { ranges }
+    }
+
+    /// values in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+            let (first, last) = (a.0.max(b.0), a.1.min(b.1));
+            if first <= last {
+                ranges.push((first, last));
+            }
+            // advance interval ending first, the other may overlap next one
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// values in this set but not in other
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+        for &(first, last) in &self.ranges {
+            // intervals of other ending before this one do not matter to
+            // next ones either
+            while j < other.ranges.len() && other.ranges[j].1 < first {
+                j += 1;
+            }
+            // first value of interval not yet removed nor kept
+            let mut current = first;
+            loop {
+                match other.ranges.get(j) {
+                    Some(&(start, end)) if start <= last => {
+                        if start > current {
+                            ranges.push((current, start - T::ONE));
+                        }
+                        if end >= last {
+                            // rest of interval removed, other interval may
+                            // still overlap next one
+                            break;
+                        }
+                        current = end + T::ONE;
+                        j += 1;
+                    }
+                    _ => {
+                        ranges.push((current, last));
+                        break;
+                    }
+                }
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.ranges.partition_point(|r| r.1 < value);
+        self.ranges.get(idx).is_some_and(|r| r.0 <= value)
+    }
+
+    /// number of values in set, which may not fit in `T`, saturating only
+    /// for every value of a 128-bit type
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().fold(0, |len: u128, &(first, last)| {
+            len.saturating_add(T::count(first, last))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// number of disjoint intervals
+    pub fn intervals(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// iterate through intervals in increasing order, an interval reaching
+    /// the largest value of `T` overflows, see [`IntervalSet::iter_inclusive`]
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges
+            .iter()
+            .map(|&(first, last)| first..last + T::ONE)
+    }
+
+    pub fn iter_inclusive(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(first, last)| first..=last)
+    }
+}
+
+impl<T> FromIterator<Range<T>> for IntervalSet<T>
+where
+    T: Bound,
+{
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+impl<T> FromIterator<RangeInclusive<T>> for IntervalSet<T>
+where
+    T: Bound,
+{
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert_inclusive(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet<i64>) -> Vec<Range<i64>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn insert() {
+        let mut set: IntervalSet<i64> = [10..15, 1..3, 20..25].into_iter().collect();
+        assert_eq!(ranges(&set), vec![1..3, 10..15, 20..25]);
+
+        // adjacent intervals are merged
+        set.insert(3..5);
+        assert_eq!(ranges(&set), vec![1..5, 10..15, 20..25]);
+        // interval spanning several ones
+        set.insert(12..21);
+        assert_eq!(ranges(&set), vec![1..5, 10..25]);
+        // empty interval
+        set.insert(8..8);
+        assert_eq!(set.intervals(), 2);
+        assert_eq!(set.len(), 19);
+    }
+
+    #[test]
+    fn remove() {
+        let mut set: IntervalSet<i64> = [0..10, 20..30].into_iter().collect();
+        set.remove(3..5);
+        assert_eq!(ranges(&set), vec![0..3, 5..10, 20..30]);
+        set.remove(8..25);
+        assert_eq!(ranges(&set), vec![0..3, 5..8, 25..30]);
+        set.remove(-5..100);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn inclusive() {
+        let set: IntervalSet<usize> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+        assert_eq!(
+            set.iter_inclusive().collect::<Vec<_>>(),
+            vec![3..=5, 10..=20]
+        );
+        assert_eq!(set.len(), 14);
+        assert!(set.contains(5));
+        assert!(!set.contains(6));
+        assert!(set.contains(10));
+        assert!(!set.contains(21));
+
+        let mut set = set;
+        set.remove_inclusive(4..=4);
+        assert_eq!(
+            set.iter_inclusive().collect::<Vec<_>>(),
+            vec![3..=3, 5..=5, 10..=20]
+        );
+    }
+
+    #[test]
+    fn operations() {
+        let a: IntervalSet<i64> = [0..10, 20..30].into_iter().collect();
+        let b: IntervalSet<i64> = [5..25, 28..40].into_iter().collect();
+        assert_eq!(ranges(&a.union(&b)), vec![0..40]);
+        assert_eq!(ranges(&a.intersection(&b)), vec![5..10, 20..25, 28..30]);
+        assert_eq!(ranges(&a.difference(&b)), vec![0..5, 25..28]);
+        assert_eq!(ranges(&b.difference(&a)), vec![10..20, 30..40]);
+        // an interval of other spanning several ones
+        let c: IntervalSet<i64> = [0..3, 5..8, 10..12, 20..22].into_iter().collect();
+        let d: IntervalSet<i64> = [2..11, 15..16].into_iter().collect();
+        assert_eq!(ranges(&c.difference(&d)), vec![0..2, 11..12, 20..22]);
+        assert_eq!(ranges(&d.difference(&c)), vec![3..5, 8..10, 15..16]);
+        assert_eq!(ranges(&c.union(&d)), vec![0..12, 15..16, 20..22]);
+        assert!(c.difference(&c).is_empty());
+        assert_eq!(c.union(&IntervalSet::new()), c);
+    }
+
+    #[test]
+    fn bounds() {
+        // intervals up to the largest value
+        let mut set: IntervalSet<u8> = [250..=255, 0..=3].into_iter().collect();
+        assert!(set.contains(255));
+        assert_eq!(set.len(), 10);
+        set.insert_inclusive(4..=249);
+        assert_eq!(set.iter_inclusive().collect::<Vec<_>>(), vec![0..=255]);
+        set.remove_inclusive(255..=255);
+        set.remove_inclusive(0..=0);
+        assert_eq!(set.iter_inclusive().collect::<Vec<_>>(), vec![1..=254]);
+
+        let full: IntervalSet<i16> = [i16::MIN..=i16::MAX].into_iter().collect();
+        let middle: IntervalSet<i16> = [-1..=1].into_iter().collect();
+        let outside = full.difference(&middle);
+        assert_eq!(
+            outside.iter_inclusive().collect::<Vec<_>>(),
+            vec![i16::MIN..=-2, 2..=i16::MAX]
+        );
+        assert_eq!(outside.union(&middle), full);
+        assert_eq!(outside.intersection(&full), outside);
+        assert_eq!(full.len(), 1 << 16);
+
+        let full: IntervalSet<u8> = [0..=255].into_iter().collect();
+        assert_eq!(full.len(), 256);
+        let set: IntervalSet<i8> = [-128..=-100, 100..=127].into_iter().collect();
+        assert_eq!(set.len(), 57);
+        assert!(set.contains(-128));
+        let full: IntervalSet<i128> = [i128::MIN..=i128::MAX].into_iter().collect();
+        assert_eq!(full.len(), u128::MAX);
+    }
+}
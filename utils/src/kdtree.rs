@@ -0,0 +1,276 @@
+use std::collections::BinaryHeap;
+
+/// A point of `D` integer coordinates
+pub type Point<const D: usize> = [i64; D];
+
+/// Squared euclidean distance between two points
+pub fn sqdist<const D: usize>(a: &Point<D>, b: &Point<D>) -> i64 {
+    a.iter().zip(b).map(|(a, b)| (a - b).pow(2)).sum()
+}
+
+/// A static k-d tree over a set of points, queries return indices of points
+/// as given to [`KdTree::new`].
+#[derive(Debug, Clone)]
+pub struct KdTree<const D: usize> {
+    points: Vec<Point<D>>,
+    /// point indices laid out as an implicit balanced tree: the node of a
+    /// slice is its middle element, split along axis `depth % D`
+    nodes: Vec<usize>,
+}
+
+impl<const D: usize> KdTree<D> {
+    pub fn new(points: Vec<Point<D>>) -> Self {
+        let mut nodes: Vec<usize> = (0..points.len()).collect();
+        Self::build(&points, &mut nodes, 0);
+        Self { points, nodes }
+    }
+
+    fn build(points: &[Point<D>], nodes: &mut [usize], depth: usize) {
+        if nodes.len() <= 1 {
+            return;
+        }
+        let axis = depth % D;
+        let mid = nodes.len() / 2;
+        nodes.select_nth_unstable_by_key(mid, |&idx| points[idx][axis]);
+        let (left, right) = nodes.split_at_mut(mid);
+        Self::build(points, left, depth + 1);
+        Self::build(points, &mut right[1..], depth + 1);
+    }
+
+    pub fn points(&self) -> &[Point<D>] {
+        &self.points
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// `k` points closest to `target` as (index, squared distance), closest first
+    pub fn nearest(&self, target: &Point<D>, k: usize) -> Vec<(usize, i64)> {
+        let mut best = BinaryHeap::new();
+        if k > 0 {
+            self.nearest_in(&self.nodes, 0, target, k, &mut best);
+        }
+        let mut best: Vec<(usize, i64)> = best
+            .into_iter()
+            .map(|(sqdist, idx)| (idx, sqdist))
+            .collect();
+        best.sort_unstable_by_key(|&(idx, sqdist)| (sqdist, idx));
+        best
+    }
+
+    /// visit subtree keeping `k` closest points in a max-heap of (sqdist, index)
+    fn nearest_in(
+        &self,
+        nodes: &[usize],
+        depth: usize,
+        target: &Point<D>,
+        k: usize,
+        best: &mut BinaryHeap<(i64, usize)>,
+    ) {
+        if nodes.is_empty() {
+            return;
+        }
+        let mid = nodes.len() / 2;
+        let idx = nodes[mid];
+        let point = &self.points[idx];
+
+        best.push((sqdist(point, target), idx));
+        if best.len() > k {
+            best.pop();
+        }
+
+        let axis = depth % D;
+        let diff = target[axis] - point[axis];
+        let (near, far) = if diff < 0 {
+            (&nodes[..mid], &nodes[mid + 1..])
+        } else {
+            (&nodes[mid + 1..], &nodes[..mid])
+        };
+        self.nearest_in(near, depth + 1, target, k, best);
+        // points across splitting plane are at least diff away
+        if best.len() < k || diff.pow(2) <= best.peek().unwrap().0 {
+            self.nearest_in(far, depth + 1, target, k, best);
+        }
+    }
+
+    /// indices of points at squared distance `max_sqdist` or less from `target`
+    pub fn within(&self, target: &Point<D>, max_sqdist: i64) -> Vec<usize> {
+        let mut found = vec![];
+        self.within_in(&self.nodes, 0, target, max_sqdist, &mut found);
+        found.sort_unstable();
+        found
+    }
+
+    fn within_in(
+        &self,
+        nodes: &[usize],
+        depth: usize,
+        target: &Point<D>,
+        max_sqdist: i64,
+        found: &mut Vec<usize>,
+    ) {
+        if nodes.is_empty() {
+            return;
+        }
+        let mid = nodes.len() / 2;
+        let idx = nodes[mid];
+        let point = &self.points[idx];
+        if sqdist(point, target) <= max_sqdist {
+            found.push(idx);
+        }
+
+        let axis = depth % D;
+        let diff = target[axis] - point[axis];
+        if diff <= 0 || diff.pow(2) <= max_sqdist {
+            self.within_in(&nodes[..mid], depth + 1, target, max_sqdist, found);
+        }
+        if diff >= 0 || diff.pow(2) <= max_sqdist {
+            self.within_in(&nodes[mid + 1..], depth + 1, target, max_sqdist, found);
+        }
+    }
+
+    /// `k` closest pairs of distinct points as (a, b, squared distance) with
+    /// `a < b`, closest first, ties broken by indices
+    pub fn closest_pairs(&self, k: usize) -> Vec<(usize, usize, i64)> {
+        let mut best = BinaryHeap::new();
+        if k > 0 {
+            for a in 0..self.len() {
+                self.pairs_in(&self.nodes, 0, a, k, &mut best);
+            }
+        }
+        let mut pairs: Vec<(usize, usize, i64)> = best
+            .into_iter()
+            .map(|(sqdist, a, b)| (a, b, sqdist))
+            .collect();
+        pairs.sort_unstable_by_key(|&(a, b, sqdist)| (sqdist, a, b));
+        pairs
+    }
+
+    /// visit subtree for pairs `(a, b)` with `a < b`, keeping the `k` closest
+    /// pairs overall in a max-heap of (sqdist, a, b)
+    fn pairs_in(
+        &self,
+        nodes: &[usize],
+        depth: usize,
+        a: usize,
+        k: usize,
+        best: &mut BinaryHeap<(i64, usize, usize)>,
+    ) {
+        if nodes.is_empty() {
+            return;
+        }
+        let mid = nodes.len() / 2;
+        let idx = nodes[mid];
+        let point = &self.points[idx];
+        let target = &self.points[a];
+
+        if a < idx {
+            let pair = (sqdist(point, target), a, idx);
+            if best.len() < k {
+                best.push(pair);
+            } else if pair < *best.peek().unwrap() {
+                best.pop();
+                best.push(pair);
+            }
+        }
+
+        let axis = depth % D;
+        let diff = target[axis] - point[axis];
+        let (near, far) = if diff < 0 {
+            (&nodes[..mid], &nodes[mid + 1..])
+        } else {
+            (&nodes[mid + 1..], &nodes[..mid])
+        };
+        self.pairs_in(near, depth + 1, a, k, best);
+        // points across splitting plane are at least diff away, pairs as
+        // far as the k-th closest one may still win on indices
+        if best.len() < k || diff.pow(2) <= best.peek().unwrap().0 {
+            self.pairs_in(far, depth + 1, a, k, best);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points() -> Vec<Point<3>> {
+        // deterministic pseudo-random cloud
+        let mut seed = 12345i64;
+        let mut next = move || {
+            seed = (seed * 1103515245 + 12345) % (1 << 31);
+            seed % 1000
+        };
+        (0..200).map(|_| [next(), next(), next()]).collect()
+    }
+
+    #[test]
+    fn nearest() {
+        let points = points();
+        let tree = KdTree::new(points.clone());
+        for target in [[0, 0, 0], [500, 500, 500], points[17]] {
+            let mut expected: Vec<(usize, i64)> = points
+                .iter()
+                .enumerate()
+                .map(|(idx, p)| (idx, sqdist(p, &target)))
+                .collect();
+            expected.sort_unstable_by_key(|&(idx, sqdist)| (sqdist, idx));
+            expected.truncate(5);
+            assert_eq!(tree.nearest(&target, 5), expected);
+        }
+        assert_eq!(tree.nearest(&points[17], 1), vec![(17, 0)]);
+    }
+
+    #[test]
+    fn within() {
+        let points = points();
+        let tree = KdTree::new(points.clone());
+        let target = [300, 600, 200];
+        let expected: Vec<usize> = (0..points.len())
+            .filter(|&idx| sqdist(&points[idx], &target) <= 200 * 200)
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(tree.within(&target, 200 * 200), expected);
+    }
+
+    #[test]
+    fn closest_pairs() {
+        let points = points();
+        let tree = KdTree::new(points.clone());
+        let mut expected = vec![];
+        for a in 0..points.len() {
+            for b in a + 1..points.len() {
+                expected.push((a, b, sqdist(&points[a], &points[b])));
+            }
+        }
+        expected.sort_unstable_by_key(|&(a, b, sqdist)| (sqdist, a, b));
+        expected.truncate(50);
+        assert_eq!(tree.closest_pairs(50), expected);
+
+        // many pairs at the same distance on a grid
+        let grid: Vec<Point<2>> = (0..100).map(|i| [i % 10, i / 10]).collect();
+        let tree = KdTree::new(grid.clone());
+        let mut expected = vec![];
+        for a in 0..grid.len() {
+            for b in a + 1..grid.len() {
+                expected.push((a, b, sqdist(&grid[a], &grid[b])));
+            }
+        }
+        expected.sort_unstable_by_key(|&(a, b, sqdist)| (sqdist, a, b));
+        for k in [1, 50, 180, 200, 400] {
+            assert_eq!(tree.closest_pairs(k), expected[..k]);
+        }
+        assert!(tree.closest_pairs(0).is_empty());
+
+        let tree = KdTree::new(vec![[0, 0], [5, 5], [1, 1]]);
+        assert_eq!(
+            tree.closest_pairs(10),
+            vec![(0, 2, 2), (1, 2, 32), (0, 1, 50)]
+        );
+    }
+}
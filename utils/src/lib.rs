@@ -1,6 +1,22 @@
+pub mod alloc;
+pub mod answers;
 pub mod arrays;
 pub mod asciimap;
+pub mod columns;
+pub mod examples;
+pub mod graph;
 pub mod inputs;
+pub mod intervals;
+pub mod kdtree;
+pub mod mst;
+pub mod num;
+pub mod order;
+pub mod piecewise;
+pub mod polynomial;
+pub mod search;
+pub mod solution;
+pub mod trace;
+pub mod unionfind;
 
 pub fn swap<T: Copy>(vec: &mut Vec<T>, i: usize, j: usize) {
     let a = vec[i];
@@ -0,0 +1,160 @@
+use crate::kdtree::{KdTree, Point};
+use crate::unionfind::UnionFind;
+
+/// Weighted edge between nodes `a` and `b`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edge<W> {
+    pub a: usize,
+    pub b: usize,
+    pub weight: W,
+}
+
+impl<W> From<(usize, usize, W)> for Edge<W> {
+    fn from((a, b, weight): (usize, usize, W)) -> Self {
+        Self { a, b, weight }
+    }
+}
+
+/// Kruskal's algorithm, stepping through edges by increasing weight.
+///
+/// Iterating gives edges accepted in the spanning forest, in acceptance
+/// order, while [`Kruskal::forest`] gives components reached so far.
+#[derive(Debug, Clone)]
+pub struct Kruskal<W> {
+    /// edges sorted by decreasing weight, next one to consider is last
+    edges: Vec<Edge<W>>,
+    forest: UnionFind,
+}
+
+impl<W: Copy + Ord> Kruskal<W> {
+    /// spanning forest of nodes `0..n` over given edges
+    pub fn new(n: usize, edges: impl IntoIterator<Item = impl Into<Edge<W>>>) -> Self {
+        let mut edges: Vec<Edge<W>> = edges.into_iter().map(Into::into).collect();
+        // stable sort then reverse, edges of same weight are considered in given order
+        edges.sort_by_key(|edge| edge.weight);
+        edges.reverse();
+        Self {
+            edges,
+            forest: UnionFind::new(n),
+        }
+    }
+
+    /// consider next lightest edge, return it and whether it joined two components
+    pub fn consider(&mut self) -> Option<(Edge<W>, bool)> {
+        let edge = self.edges.pop()?;
+        let accepted = self.forest.union(edge.a, edge.b).is_some();
+        Some((edge, accepted))
+    }
+
+    /// components of nodes joined by edges accepted so far
+    pub fn forest(&self) -> &UnionFind {
+        &self.forest
+    }
+
+    /// true once every node is in a single component
+    pub fn is_spanning(&self) -> bool {
+        self.forest.components() <= 1
+    }
+}
+
+impl<W: Copy + Ord> Iterator for Kruskal<W> {
+    type Item = Edge<W>;
+
+    /// next accepted edge, `None` once spanning or out of edges
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.is_spanning() {
+            if let (edge, true) = self.consider()? {
+                return Some(edge);
+            }
+        }
+        None
+    }
+}
+
+/// Edges of a minimum spanning forest of nodes `0..n`, in acceptance order
+pub fn minimum_spanning_tree<W: Copy + Ord>(
+    n: usize,
+    edges: impl IntoIterator<Item = impl Into<Edge<W>>>,
+) -> Vec<Edge<W>> {
+    Kruskal::new(n, edges).collect()
+}
+
+/// Edge which finally connects all nodes `0..n` together, `None` if edges
+/// do not connect them all
+pub fn connecting_edge<W: Copy + Ord>(
+    n: usize,
+    edges: impl IntoIterator<Item = impl Into<Edge<W>>>,
+) -> Option<Edge<W>> {
+    let mut kruskal = Kruskal::new(n, edges);
+    let last = kruskal.by_ref().last();
+    kruskal.is_spanning().then_some(last).flatten()
+}
+
+/// Edges of a minimum spanning tree of points, weighted by squared distance,
+/// in acceptance order.
+///
+/// Only the closest pairs are considered, doubling their number until they
+/// connect every point: Kruskal's algorithm never needs edges beyond those
+/// it accepts last.
+pub fn point_spanning_tree<const D: usize>(points: &[Point<D>]) -> Vec<Edge<i64>> {
+    let n = points.len();
+    let tree = KdTree::new(points.to_vec());
+    let all = n * n.saturating_sub(1) / 2;
+    let mut k = n;
+    loop {
+        let edges = minimum_spanning_tree(n, tree.closest_pairs(k));
+        if edges.len() + 1 >= n || k >= all {
+            return edges;
+        }
+        k *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// square with a diagonal
+    const EDGES: [(usize, usize, u32); 5] = [(0, 1, 1), (1, 2, 2), (2, 3, 1), (3, 0, 3), (0, 2, 2)];
+
+    #[test]
+    fn spanning_tree() {
+        let tree = minimum_spanning_tree(4, EDGES);
+        let weights: Vec<u32> = tree.iter().map(|edge| edge.weight).collect();
+        assert_eq!(weights, vec![1, 1, 2]);
+        // edges of same weight are considered in given order
+        assert_eq!((tree[2].a, tree[2].b), (1, 2));
+        assert_eq!(connecting_edge(4, EDGES), Some(Edge::from((1, 2, 2))));
+        assert_eq!(connecting_edge(5, EDGES), None);
+    }
+
+    #[test]
+    fn steps() {
+        let mut kruskal = Kruskal::new(5, EDGES);
+        assert_eq!(kruskal.consider(), Some((Edge::from((0, 1, 1)), true)));
+        assert_eq!(kruskal.consider(), Some((Edge::from((2, 3, 1)), true)));
+        assert_eq!(kruskal.forest().components(), 3);
+        assert_eq!(kruskal.forest().largest(2), vec![2, 2]);
+        kruskal.consider();
+        assert_eq!(kruskal.consider(), Some((Edge::from((0, 2, 2)), false)));
+        assert!(!kruskal.is_spanning());
+    }
+
+    #[test]
+    fn points() {
+        let points = [[0, 0], [10, 0], [0, 1], [10, 2]];
+        let tree = point_spanning_tree(&points);
+        assert_eq!(tree.len(), 3);
+        let last = tree.last().unwrap();
+        assert_eq!((last.a, last.b, last.weight), (0, 1, 100));
+
+        // a far cluster is only reached through pairs beyond the first n
+        let mut points: Vec<[i64; 2]> = (0..20).map(|i| [i % 5, i / 5]).collect();
+        points.push([1000, 1000]);
+        let tree = point_spanning_tree(&points);
+        assert_eq!(tree.len(), 20);
+        assert_eq!(tree.last().unwrap().weight, 996 * 996 + 997 * 997);
+        assert!(point_spanning_tree::<2>(&[]).is_empty());
+        assert!(point_spanning_tree(&[[1, 1]]).is_empty());
+    }
+}
@@ -0,0 +1,415 @@
+use std::ops::{Div, Mul, RangeInclusive, Rem};
+
+/// Primitive integers, for [`gcd`] and [`lcm`]
+pub trait Integer:
+    Copy + Ord + Default + Rem<Output = Self> + Div<Output = Self> + Mul<Output = Self>
+{
+    fn abs(self) -> Self;
+}
+
+macro_rules! integer {
+    (signed: $($t:ty),*; unsigned: $($u:ty),*) => {
+        $(impl Integer for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+        })*
+        $(impl Integer for $u {
+            fn abs(self) -> Self {
+                self
+            }
+        })*
+    };
+}
+
+integer!(signed: i8, i16, i32, i64, i128, isize; unsigned: u8, u16, u32, u64, u128, usize);
+
+/// greatest common divisor, non-negative, `gcd(0, 0)` is 0
+///
+/// Panics on the minimum of a signed type, whose absolute value does not fit.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::default() {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// least common multiple, non-negative, 0 if either value is 0
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::default() || b == T::default() {
+        return T::default();
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// greatest common divisor of every values, 0 if there are none
+pub fn gcd_all<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::default(), gcd)
+}
+
+/// least common multiple of every values, `None` if there are none
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values.into_iter().reduce(lcm)
+}
+
+/// extended Euclid algorithm, return `(g, x, y)` such that `a*x + b*y = g`
+/// where `g` is the non-negative gcd of `a` and `b`
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// inverse of `a` modulo `m` in `0..m`, `None` if `a` and `m` are not coprime
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// `base` to the power `exp` modulo `m` in `0..m`, `m` must be below 2^63
+/// for intermediate products not to overflow
+pub fn mod_pow(base: i128, mut exp: u64, m: i128) -> i128 {
+    let mut base = base.rem_euclid(m);
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result
+}
+
+/// Chinese remainder theorem: solve `x = residue (mod modulus)` for every
+/// given congruence, moduli need not be coprime.
+///
+/// Return `(x, m)` where `x` in `0..m` is the smallest solution and `m` the
+/// lcm of moduli, every solution being `x` plus a multiple of `m`. `None` if
+/// congruences are incompatible, a modulus is not positive or intermediate
+/// values overflow an `i128`.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    let mut solution = (0, 1);
+    for (residue, modulus) in congruences {
+        if modulus <= 0 {
+            return None;
+        }
+        let (x, m) = solution;
+        // x + m*k = residue (mod modulus), solvable iff gcd divides difference
+        let (g, p, _) = extended_gcd(m, modulus);
+        let diff = residue.rem_euclid(modulus) - x;
+        if diff % g != 0 {
+            return None;
+        }
+        let step = modulus / g;
+        let k = (diff / g % step).checked_mul(p)?.rem_euclid(step);
+        // k < step so x + m*k < m*step
+        let lcm = m.checked_mul(step)?;
+        solution = (x + m * k, lcm);
+    }
+    Some(solution)
+}
+
+/// integer square root, largest `r` with `r*r <= n`
+pub fn isqrt(n: u128) -> u128 {
+    n.isqrt()
+}
+
+/// integers `x` with `a*x*x + b*x + c < 0`, for `a > 0`, `None` if there
+/// are none, bounds are clamped to the `i128` range
+pub fn quadratic_negative(a: i128, b: i128, c: i128) -> Option<RangeInclusive<i128>> {
+    assert!(a > 0, "quadratic coefficient must be positive");
+    // a*x*x + b*x + c < 0 iff (2*a*x + b)^2 < b*b - 4*a*c
+    let (Some(discriminant), Some(a2)) = (
+        b.checked_mul(b)
+            .zip(a.checked_mul(c).and_then(|ac| ac.checked_mul(4)))
+            .and_then(|(bb, ac4)| bb.checked_sub(ac4)),
+        a.checked_mul(2),
+    ) else {
+        return quadratic_negative_wide(a, b, c);
+    };
+    if discriminant <= 0 {
+        return None;
+    }
+    // |2*a*x + b| <= r
+    let r = isqrt(discriminant as u128 - 1) as i128;
+    let low = -((r + b).div_euclid(a2));
+    let high = (r - b).div_euclid(a2);
+    (low <= high).then_some(low..=high)
+}
+
+/// [`quadratic_negative`] when the discriminant does not fit in an `i128`:
+/// negative values are around the vertex, search both ends from there
+fn quadratic_negative_wide(a: i128, b: i128, c: i128) -> Option<RangeInclusive<i128>> {
+    let negative = |x| quadratic_is_negative(a, b, c, x);
+    // m = floor(-b / 2a) = -ceil(ceil(b / a) / 2), then the vertex is in m..m+1
+    let q = b.div_euclid(a) + (b.rem_euclid(a) != 0) as i128;
+    let m = -(q.div_euclid(2) + q.rem_euclid(2));
+    let inside = [m, m + 1].into_iter().find(|&x| negative(x))?;
+    let low = if negative(i128::MIN) {
+        i128::MIN
+    } else {
+        first_true(i128::MIN, inside, negative)
+    };
+    let high = if negative(i128::MAX) {
+        i128::MAX
+    } else {
+        first_true(inside, i128::MAX, |x| !negative(x)) - 1
+    };
+    Some(low..=high)
+}
+
+/// exact sign of `a*x*x + b*x + c < 0` for `a > 0`, without overflowing
+fn quadratic_is_negative(a: i128, b: i128, c: i128, x: i128) -> bool {
+    if x == 0 {
+        return c < 0;
+    }
+    // with y = |x|: x*(a*x + b) = y*(a*y + s) where s is b signed as x, and
+    // y*(a*y + s) < -c iff a*y + s < ceil(-c / y) iff a*y < -(s + floor(c / y))
+    let y = x.unsigned_abs();
+    let Some(s) = (if x > 0 { Some(b) } else { b.checked_neg() }) else {
+        // s = 2^127 is above any -c / y
+        return false;
+    };
+    let floor = match i128::try_from(y) {
+        Ok(y) => c.div_euclid(y),
+        // y = 2^127
+        Err(_) => -((c < 0) as i128),
+    };
+    let ay = (a as u128).checked_mul(y);
+    match s.checked_add(floor) {
+        Some(k) => k < 0 && ay.is_some_and(|ay| ay < k.unsigned_abs()),
+        // both negative, -k is 2^128 at most
+        None if s < 0 => match s.unsigned_abs().checked_add(floor.unsigned_abs()) {
+            Some(k) => ay.is_some_and(|ay| ay < k),
+            None => ay.is_some(),
+        },
+        None => false,
+    }
+}
+
+/// first `x` in `low..=high` where `pred` holds, `pred` being false at `low`,
+/// true at `high` and changing only once in between
+fn first_true(mut low: i128, mut high: i128, pred: impl Fn(i128) -> bool) -> i128 {
+    while high.abs_diff(low) > 1 {
+        let mid = low.wrapping_add_unsigned(high.abs_diff(low) / 2);
+        if pred(mid) {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    high
+}
+
+/// number of integers `x` with `x*(total - x) > threshold`, exact over the
+/// whole `u128` range
+pub fn count_product_above(total: u128, threshold: u128) -> u128 {
+    // with y = 2*x - total: x*(total - x) > threshold iff y*y < total^2 - 4*threshold
+    let (Some(square), Some(threshold4)) = (total.checked_mul(total), threshold.checked_mul(4))
+    else {
+        return count_product_above_wide(total, threshold);
+    };
+    if square <= threshold4 {
+        return 0;
+    }
+    // |y| <= r, where y has the parity of total
+    let r = isqrt(square - threshold4 - 1);
+    if r % 2 == total % 2 {
+        r + 1
+    } else {
+        r
+    }
+}
+
+/// [`count_product_above`] when `total^2` does not fit in 128 bits: the
+/// product increases up to `total / 2`, search the first `x` above threshold
+fn count_product_above_wide(total: u128, threshold: u128) -> u128 {
+    // a product overflowing is above any threshold
+    let above = |x: u128| x.checked_mul(total - x).is_none_or(|p| p > threshold);
+    let (mut low, mut high) = (0, total / 2);
+    if !above(high) {
+        return 0;
+    }
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if above(mid) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    // solutions are symmetric around total / 2
+    total - 2 * low + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0u32, 7), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4i64, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(lcm_all([2u64, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all(Vec::<u64>::new()), None);
+        assert_eq!(gcd(i64::MIN + 1, 3), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gcd_min() {
+        gcd(i32::MIN, 2);
+    }
+
+    #[test]
+    fn euclid() {
+        for (a, b) in [(240, 46), (-240, 46), (17, 0), (0, 5), (7, -13)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn pow() {
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(-2, 3, 7), 6);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+        // Fermat's little theorem
+        let p = 1_000_000_007;
+        assert_eq!(mod_pow(123_456_789, p as u64 - 1, p), 1);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // non-coprime moduli
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(1, 0)]), None);
+        assert_eq!(crt([(2, 3), (1, -5)]), None);
+
+        // large coprime moduli, with a product close to 2^124
+        let (m1, m2) = ((1 << 62) + 1, (1 << 62) - 1);
+        let (x, m) = crt([(3, m1), (-5, m2)]).unwrap();
+        assert_eq!((x % m1, x % m2, m), (3, m2 - 5, m1 * m2));
+        assert!((0..m).contains(&x));
+        // lcm overflows
+        assert_eq!(crt([(1, (1 << 64) + 1), (2, (1 << 64) - 1)]), None);
+        let min = i128::MIN.rem_euclid(5);
+        assert_eq!(crt([(2, 7), (i128::MIN, 5)]), crt([(2, 7), (min, 5)]));
+    }
+
+    #[test]
+    fn quadratic() {
+        // (x - 1)(x - 4) < 0
+        assert_eq!(quadratic_negative(1, -5, 4), Some(2..=3));
+        // (2x + 1)(x - 3) < 0
+        assert_eq!(quadratic_negative(2, -5, -3), Some(0..=2));
+        assert_eq!(quadratic_negative(1, 0, 0), None);
+        assert_eq!(quadratic_negative(1, 0, -1), Some(0..=0));
+        for a in 1..6 {
+            for b in -20..20 {
+                for c in -20..20 {
+                    let negative = (-50..50).filter(|x| a * x * x + b * x + c < 0);
+                    let expected = negative
+                        .clone()
+                        .min()
+                        .map(|low| low..=negative.max().unwrap());
+                    assert_eq!(quadratic_negative(a, b, c), expected);
+                    assert_eq!(quadratic_negative_wide(a, b, c), expected);
+                }
+            }
+        }
+        // coefficients near u64::MAX, the discriminant overflows an i128
+        let max = u64::MAX as i128;
+        assert_eq!(quadratic_negative(1, -max, max), Some(2..=max - 2));
+        assert_eq!(quadratic_negative(max, max, -max), Some(-1..=0));
+        assert_eq!(quadratic_negative(max, -max - 1, 0), Some(1..=1));
+        // x*x < 2^127
+        let r = isqrt(i128::MAX as u128) as i128;
+        assert_eq!(quadratic_negative(1, 0, i128::MIN), Some(-r..=r));
+        assert_eq!(quadratic_negative(i128::MAX, 0, -1), Some(0..=0));
+        assert_eq!(quadratic_negative(i128::MAX, 0, 1), None);
+        // bounds clamped to the i128 range
+        assert_eq!(quadratic_negative(1, i128::MIN, 0), Some(1..=i128::MAX));
+        assert_eq!(
+            quadratic_negative(1, i128::MAX, 0),
+            Some(1 - i128::MAX..=-1)
+        );
+        for (a, b, c) in [
+            (3, -(1 << 60), 5),
+            (1 << 40, 1 << 50, -(1 << 80)),
+            (7, 0, -(1 << 120)),
+        ] {
+            assert_eq!(
+                quadratic_negative(a, b, c),
+                quadratic_negative_wide(a, b, c)
+            );
+        }
+
+        // races of 2023 day 6 example
+        assert_eq!(count_product_above(7, 9), 4);
+        assert_eq!(count_product_above(15, 40), 8);
+        assert_eq!(count_product_above(30, 200), 9);
+        assert_eq!(count_product_above(71530, 940200), 71503);
+        assert_eq!(count_product_above(4, 4), 0);
+        for total in 0..40 {
+            for threshold in 0..400 {
+                let expected = (0..=total).filter(|x| x * (total - x) > threshold).count();
+                assert_eq!(count_product_above(total, threshold), expected as u128);
+            }
+        }
+        // large enough for floating point roots to be off
+        let total = 3_000_000_000u128;
+        let threshold = (total / 2) * (total / 2) - 1;
+        assert_eq!(count_product_above(total, threshold), 1);
+        assert_eq!(
+            count_product_above(u64::MAX as u128, u64::MAX as u128),
+            u64::MAX as u128 - 3
+        );
+        // beyond 64 bits, where total^2 overflows
+        assert_eq!(count_product_above(u128::MAX, u128::MAX), u128::MAX - 3);
+        assert_eq!(count_product_above(1 << 100, 1 << 100), (1 << 100) - 3);
+        assert_eq!(count_product_above(u128::MAX, 0), u128::MAX - 1);
+        for total in [1 << 64, 1 << 65, 3 << 70] {
+            for threshold in [0, 1 << 64, 1 << 120, u128::MAX / 4, u128::MAX] {
+                assert_eq!(
+                    count_product_above_wide(total, threshold),
+                    count_product_above(total, threshold)
+                );
+            }
+        }
+        for total in 0..40 {
+            for threshold in 0..400 {
+                assert_eq!(
+                    count_product_above_wide(total, threshold),
+                    count_product_above(total, threshold)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::graph::{Graph, NodeId};
+
+/// Rules which can not all be satisfied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderError<T> {
+    /// both `a` before `b` and `b` before `a`
+    Contradiction(T, T),
+    /// each element must come before the next one, and the last before the first
+    Cycle(Vec<T>),
+}
+
+impl<T: Debug> std::fmt::Display for OrderError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Contradiction(a, b) => {
+                write!(f, "{a:?} must come both before and after {b:?}")
+            }
+            Self::Cycle(cycle) => write!(f, "cyclic rules between {cycle:?}"),
+        }
+    }
+}
+
+impl<T: Debug> std::error::Error for OrderError<T> {}
+
+/// Precedence graph of rules `(a, b)` stating `a` comes before `b`
+#[derive(Debug, Clone)]
+pub struct Precedence<T> {
+    /// nodes each node must precede
+    successors: Vec<Vec<NodeId>>,
+    /// element of each node, in order of first appearance in rules
+    elements: Vec<T>,
+    ids: HashMap<T, NodeId>,
+}
+
+impl<T: Copy + Eq + Hash> Precedence<T> {
+    pub fn new(rules: impl IntoIterator<Item = (T, T)>) -> Self {
+        let mut precedence = Self {
+            successors: vec![],
+            elements: vec![],
+            ids: HashMap::new(),
+        };
+        for (a, b) in rules {
+            let (a, b) = (precedence.node(a), precedence.node(b));
+            if !precedence.successors[a].contains(&b) {
+                precedence.successors[a].push(b);
+            }
+        }
+        precedence
+    }
+
+    /// rules given by edges of `graph`, `element` being the element named
+    /// by each node
+    pub fn from_graph<L>(graph: &Graph<L>, element: impl Fn(&str) -> T) -> Self {
+        let elements: Vec<T> = graph.nodes().map(|id| element(graph.name(id))).collect();
+        let elements = &elements;
+        Self::new(
+            graph
+                .nodes()
+                .flat_map(|a| graph.successors(a).map(move |b| (elements[a], elements[b]))),
+        )
+    }
+
+    /// node of element, created if it does not exist yet
+    fn node(&mut self, x: T) -> NodeId {
+        if let Some(&id) = self.ids.get(&x) {
+            return id;
+        }
+        let id = self.elements.len();
+        self.elements.push(x);
+        self.successors.push(vec![]);
+        self.ids.insert(x, id);
+        id
+    }
+
+    /// elements which must come after `x`
+    fn successors(&self, x: T) -> impl Iterator<Item = T> + '_ {
+        self.ids
+            .get(&x)
+            .into_iter()
+            .flat_map(|&id| &self.successors[id])
+            .map(|&id| self.elements[id])
+    }
+
+    /// number of distinct rules
+    pub fn len(&self) -> usize {
+        self.successors.iter().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// true if a rule states `a` comes before `b`
+    pub fn before(&self, a: T, b: T) -> bool {
+        match (self.ids.get(&a), self.ids.get(&b)) {
+            (Some(&a), Some(&b)) => self.successors[a].contains(&b),
+            _ => false,
+        }
+    }
+
+    /// order of `a` and `b` according to rules directly relating them, `Equal`
+    /// if there are none
+    pub fn compare(&self, a: T, b: T) -> Ordering {
+        if self.before(a, b) {
+            Ordering::Less
+        } else if self.before(b, a) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// true if no element of `seq` comes after one it should precede
+    pub fn is_ordered(&self, seq: &[T]) -> bool {
+        let mut first = HashMap::new();
+        for (i, &x) in seq.iter().enumerate() {
+            first.entry(x).or_insert(i);
+        }
+        seq.iter().enumerate().all(|(i, &x)| {
+            self.successors(x)
+                .all(|next| first.get(&next).is_none_or(|&j| j > i))
+        })
+    }
+
+    /// sort `seq` in place following rules, see [`Precedence::topological_sort`],
+    /// `seq` is left unchanged if rules between its elements have a cycle
+    pub fn sort_by_rules(&self, seq: &mut [T]) -> Result<(), OrderError<T>> {
+        let sorted = self.topological_sort(seq)?;
+        seq.copy_from_slice(&sorted);
+        Ok(())
+    }
+
+    /// order `seq` following rules between its elements, elements which are
+    /// not constrained keep their relative order
+    pub fn topological_sort(&self, seq: &[T]) -> Result<Vec<T>, OrderError<T>> {
+        let mut positions: HashMap<T, Vec<usize>> = HashMap::new();
+        for (i, &x) in seq.iter().enumerate() {
+            positions.entry(x).or_default().push(i);
+        }
+        // positions which must come after each position
+        let mut successors = vec![vec![]; seq.len()];
+        let mut incoming = vec![0; seq.len()];
+        for (i, &a) in seq.iter().enumerate() {
+            for b in self.successors(a) {
+                for &j in positions.get(&b).into_iter().flatten() {
+                    successors[i].push(j);
+                    incoming[j] += 1;
+                }
+            }
+        }
+
+        // Kahn's algorithm, taking earliest ready position first
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..seq.len())
+            .filter(|&i| incoming[i] == 0)
+            .map(Reverse)
+            .collect();
+        let mut sorted = Vec::with_capacity(seq.len());
+        while let Some(Reverse(i)) = ready.pop() {
+            sorted.push(seq[i]);
+            for &j in &successors[i] {
+                incoming[j] -= 1;
+                if incoming[j] == 0 {
+                    ready.push(Reverse(j));
+                }
+            }
+        }
+
+        if sorted.len() < seq.len() {
+            let cycle = find_cycle(&successors, &incoming);
+            return Err(self.error(cycle.into_iter().map(|i| seq[i]).collect()));
+        }
+        Ok(sorted)
+    }
+
+    /// check every rule can be satisfied at once
+    pub fn check(&self) -> Result<(), OrderError<T>> {
+        self.topological_sort(&self.elements).map(|_| ())
+    }
+
+    /// report a cycle, as a contradiction if it has only two elements
+    fn error(&self, cycle: Vec<T>) -> OrderError<T> {
+        match cycle[..] {
+            [a, b] => OrderError::Contradiction(a, b),
+            _ => OrderError::Cycle(cycle),
+        }
+    }
+}
+
+/// a cycle among nodes left with incoming edges after Kahn's algorithm,
+/// starting from its first node
+fn find_cycle(successors: &[Vec<usize>], incoming: &[usize]) -> Vec<usize> {
+    let mut predecessor = vec![None; successors.len()];
+    for (i, next) in successors.iter().enumerate() {
+        for &j in next {
+            if incoming[i] > 0 && incoming[j] > 0 {
+                predecessor[j] = Some(i);
+            }
+        }
+    }
+    // every remaining node has a remaining predecessor, walk back until
+    // a node repeats
+    let mut node = (0..incoming.len()).find(|&i| incoming[i] > 0).unwrap();
+    let mut seen = vec![false; successors.len()];
+    while !seen[node] {
+        seen[node] = true;
+        node = predecessor[node].unwrap();
+    }
+    let mut cycle = vec![node];
+    let mut previous = predecessor[node].unwrap();
+    while previous != node {
+        cycle.push(previous);
+        previous = predecessor[previous].unwrap();
+    }
+    cycle.reverse();
+    let first = (0..cycle.len()).min_by_key(|&k| cycle[k]).unwrap();
+    cycle.rotate_left(first);
+    cycle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// rules of 2024 day 5 example
+    const RULES: [(i32, i32); 21] = [
+        (47, 53),
+        (97, 13),
+        (97, 61),
+        (97, 47),
+        (75, 29),
+        (61, 13),
+        (75, 53),
+        (29, 13),
+        (97, 29),
+        (53, 29),
+        (61, 53),
+        (97, 53),
+        (61, 29),
+        (47, 13),
+        (75, 47),
+        (97, 75),
+        (47, 61),
+        (75, 61),
+        (47, 29),
+        (75, 13),
+        (53, 13),
+    ];
+
+    #[test]
+    fn ordered() {
+        let rules = Precedence::new(RULES);
+        assert_eq!(rules.len(), 21);
+        assert!(rules.is_ordered(&[75, 47, 61, 53, 29]));
+        assert!(!rules.is_ordered(&[75, 97, 47, 61, 53]));
+        assert_eq!(rules.compare(97, 75), Ordering::Less);
+        assert_eq!(rules.compare(13, 29), Ordering::Greater);
+        assert_eq!(rules.compare(1, 2), Ordering::Equal);
+        assert!(rules.check().is_ok());
+    }
+
+    #[test]
+    fn sort() {
+        let rules = Precedence::new(RULES);
+        let mut seq = [97, 13, 75, 29, 47];
+        assert_eq!(rules.topological_sort(&seq), Ok(vec![97, 75, 47, 29, 13]));
+        assert_eq!(rules.sort_by_rules(&mut seq), Ok(()));
+        assert_eq!(seq, [97, 75, 47, 29, 13]);
+        // unconstrained elements keep their order
+        assert_eq!(
+            rules.topological_sort(&[5, 13, 4, 97]),
+            Ok(vec![5, 4, 97, 13])
+        );
+    }
+
+    #[test]
+    fn from_graph() {
+        let graph = Graph::from_edge_list("47|53\n97|13\n97|47\n47|53", '|').unwrap();
+        let rules = Precedence::from_graph(&graph, |name| name.parse::<i32>().unwrap());
+        assert_eq!(rules.len(), 3);
+        assert!(rules.before(97, 47));
+        assert!(!rules.before(53, 47));
+        assert!(rules.is_ordered(&[97, 47, 53, 13]));
+        assert!(!rules.is_ordered(&[53, 97, 47]));
+        assert!(rules.check().is_ok());
+    }
+
+    #[test]
+    fn unsatisfiable() {
+        let rules = Precedence::new([(1, 2), (2, 3), (3, 1), (3, 4)]);
+        let err = rules.topological_sort(&[4, 3, 2, 1]).unwrap_err();
+        let OrderError::Cycle(cycle) = &err else {
+            panic!("{err}")
+        };
+        assert_eq!(cycle.len(), 3);
+        assert!(rules.check().is_err());
+        // rules between other elements do not matter
+        assert_eq!(rules.topological_sort(&[4, 3]), Ok(vec![3, 4]));
+
+        let mut seq = [4, 3, 2, 1];
+        assert!(rules.sort_by_rules(&mut seq).is_err());
+        assert_eq!(seq, [4, 3, 2, 1]);
+
+        let rules = Precedence::new([(1, 2), (2, 1)]);
+        assert_eq!(rules.check(), Err(OrderError::Contradiction(1, 2)));
+    }
+}
@@ -0,0 +1,252 @@
+use std::ops::Range;
+
+use crate::intervals::{Bound, IntervalSet};
+
+/// A mapping of integers shifting disjoint source intervals, values outside
+/// of every interval map to themselves.
+///
+/// ```ignore
+/// let mut map = PiecewiseMap::new();
+/// // 98 -> 50, 99 -> 51
+/// map.insert(98..100, 50);
+/// assert_eq!(map.map(99), 51);
+/// assert_eq!(map.map(10), 10);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap<T> {
+    /// (source start, source end, destination start), sorted and disjoint
+    pieces: Vec<(T, T, T)>,
+}
+
+impl<T> Default for PiecewiseMap<T> {
+    fn default() -> Self {
+        Self { pieces: vec![] }
+    }
+}
+
+impl<T> PiecewiseMap<T>
+where
+    T: Bound,
+{
+    /// identity mapping
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// map `source` onto interval starting at `destination`, values of
+    /// `source` already mapped keep their mapping
+    pub fn insert(&mut self, source: Range<T>, destination: T) {
+        let mapped: IntervalSet<T> = self.pieces.iter().map(|&(s, e, _)| s..e).collect();
+        let free = [source.clone()]
+            .into_iter()
+            .collect::<IntervalSet<T>>()
+            .difference(&mapped);
+        for range in free.iter() {
+            self.pieces.push((
+                range.start,
+                range.end,
+                destination + (range.start - source.start),
+            ));
+        }
+        self.pieces.sort_unstable();
+    }
+
+    pub fn map(&self, value: T) -> T {
+        let idx = self.pieces.partition_point(|p| p.1 <= value);
+        match self.pieces.get(idx) {
+            Some(&(start, _, destination)) if start <= value => destination + (value - start),
+            _ => value,
+        }
+    }
+
+    /// split `range` on pieces boundaries, return each sub-range along with
+    /// the value its start maps to
+    fn segments(&self, range: Range<T>) -> Vec<(Range<T>, T)> {
+        let mut segments = vec![];
+        let mut idx = self.pieces.partition_point(|p| p.1 <= range.start);
+        let mut current = range.start;
+        while current < range.end {
+            match self.pieces.get(idx) {
+                Some(&(start, end, destination)) if start <= current => {
+                    let upto = end.min(range.end);
+                    segments.push((current..upto, destination + (current - start)));
+                    current = upto;
+                    idx += 1;
+                }
+                next => {
+                    // gap before next piece maps to itself
+                    let upto = next.map_or(range.end, |p| p.0.min(range.end));
+                    segments.push((current..upto, current));
+                    current = upto;
+                }
+            }
+        }
+        segments
+    }
+
+    /// images of values of `range`, in order of the values they come from
+    pub fn map_range(&self, range: Range<T>) -> Vec<Range<T>> {
+        self.segments(range)
+            .into_iter()
+            .map(|(source, start)| start..start + (source.end - source.start))
+            .collect()
+    }
+
+    /// image of every values of set
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        set.iter().flat_map(|range| self.map_range(range)).collect()
+    }
+
+    /// mapping applying this mapping then `next` one
+    pub fn then(&self, next: &Self) -> Self {
+        let mut pieces = vec![];
+        // values shifted by this mapping, then by next one
+        for &(start, end, destination) in &self.pieces {
+            let image = destination..destination + (end - start);
+            for (sub, mapped) in next.segments(image) {
+                let source = start + (sub.start - destination);
+                pieces.push((source, source + (sub.end - sub.start), mapped));
+            }
+        }
+        // values only shifted by next mapping
+        let sources: IntervalSet<T> = self.pieces.iter().map(|&(s, e, _)| s..e).collect();
+        for &(start, end, destination) in &next.pieces {
+            let free = [start..end]
+                .into_iter()
+                .collect::<IntervalSet<T>>()
+                .difference(&sources);
+            for range in free.iter() {
+                pieces.push((range.start, range.end, destination + (range.start - start)));
+            }
+        }
+        Self::normalized(pieces)
+    }
+
+    /// inverse mapping, `None` unless mapping is a bijection: two values
+    /// mapped to the same one would have no single preimage
+    pub fn inverse(&self) -> Option<Self> {
+        let mut images: Vec<(T, T, T)> = self
+            .pieces
+            .iter()
+            .map(|&(start, end, destination)| (destination, destination + (end - start), start))
+            .collect();
+        images.sort_unstable();
+        // pieces images must not overlap each other, nor values mapped to
+        // themselves (outside of every source)
+        if images.windows(2).any(|w| w[0].1 > w[1].0) {
+            return None;
+        }
+        let sources: IntervalSet<T> = self.pieces.iter().map(|&(s, e, _)| s..e).collect();
+        let covered: IntervalSet<T> = images.iter().map(|&(s, e, _)| s..e).collect();
+        if !covered.difference(&sources).is_empty() {
+            return None;
+        }
+        Some(Self::normalized(images))
+    }
+
+    /// sort disjoint pieces, drop those mapping values to themselves and
+    /// merge contiguous pieces shifting values by the same amount
+    fn normalized(mut pieces: Vec<(T, T, T)>) -> Self {
+        pieces.sort_unstable();
+        let mut normalized: Vec<(T, T, T)> = vec![];
+        for (start, end, destination) in pieces {
+            if start == destination {
+                continue;
+            }
+            match normalized.last_mut() {
+                Some(last) if last.1 == start && last.2 + (last.1 - last.0) == destination => {
+                    last.1 = end
+                }
+                _ => normalized.push((start, end, destination)),
+            }
+        }
+        Self { pieces: normalized }
+    }
+
+    /// number of intervals which are not mapped to themselves
+    pub fn pieces(&self) -> usize {
+        self.pieces.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// seed-to-soil and soil-to-fertilizer maps of 2023 day 5 example
+    fn maps() -> (PiecewiseMap<i64>, PiecewiseMap<i64>) {
+        let mut soil = PiecewiseMap::new();
+        soil.insert(98..100, 50);
+        soil.insert(50..98, 52);
+        let mut fertilizer = PiecewiseMap::new();
+        fertilizer.insert(15..52, 0);
+        fertilizer.insert(52..54, 37);
+        fertilizer.insert(0..15, 39);
+        (soil, fertilizer)
+    }
+
+    #[test]
+    fn map() {
+        let (soil, _) = maps();
+        assert_eq!(soil.map(79), 81);
+        assert_eq!(soil.map(14), 14);
+        assert_eq!(soil.map(99), 51);
+        assert_eq!(soil.map(100), 100);
+
+        assert_eq!(soil.map_range(45..55), vec![45..50, 52..57]);
+        assert_eq!(soil.map_range(96..102), vec![98..100, 50..52, 100..102]);
+    }
+
+    #[test]
+    fn first_piece_wins() {
+        let mut map = PiecewiseMap::new();
+        map.insert(10..20, 100);
+        map.insert(15..25, 200);
+        assert_eq!(map.map(15), 105);
+        assert_eq!(map.map(20), 205);
+    }
+
+    #[test]
+    fn compose() {
+        let (soil, fertilizer) = maps();
+        let seed_to_fertilizer = soil.then(&fertilizer);
+        for seed in 0..120 {
+            assert_eq!(
+                seed_to_fertilizer.map(seed),
+                fertilizer.map(soil.map(seed)),
+                "seed {seed}"
+            );
+        }
+        let set = [79..93, 55..68].into_iter().collect::<IntervalSet<i64>>();
+        let mapped = seed_to_fertilizer.map_set(&set);
+        assert_eq!(mapped.len(), 27);
+        assert_eq!(mapped.iter().next().map(|r| r.start), Some(57));
+    }
+
+    #[test]
+    fn inverse() {
+        let (soil, fertilizer) = maps();
+        for map in [soil, fertilizer] {
+            let inverse = map.inverse().unwrap();
+            for value in 0..120 {
+                assert_eq!(inverse.map(map.map(value)), value);
+            }
+        }
+
+        // 100 is both 0 mapped and itself
+        let mut map = PiecewiseMap::new();
+        map.insert(0..10, 100);
+        assert_eq!(map.inverse(), None);
+        // 0 and 10 both map to 20
+        let mut map = PiecewiseMap::new();
+        map.insert(0..10, 20);
+        map.insert(10..20, 20);
+        map.insert(20..30, 0);
+        assert_eq!(map.inverse(), None);
+        // swapping two intervals
+        let mut map = PiecewiseMap::new();
+        map.insert(0..10, 20);
+        map.insert(20..30, 0);
+        assert_eq!(map.inverse(), Some(map.clone()));
+    }
+}
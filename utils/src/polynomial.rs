@@ -0,0 +1,201 @@
+use crate::num::gcd;
+
+/// Polynomial of minimal degree through a sequence of values taken at
+/// `x = 0, 1, 2, ...`, kept in Newton form as leading finite differences.
+///
+/// ```
+/// use utils::polynomial::Polynomial;
+///
+/// let p = Polynomial::fit(&[1, 3, 6, 10, 15, 21]);
+/// assert_eq!(p.degree(), 2);
+/// assert_eq!(p.next(1), Some(28));
+/// assert_eq!(p.previous(1), Some(0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    /// k-th finite difference at `x = 0`, up to the last non-zero one
+    differences: Vec<i128>,
+    /// number of fitted values
+    len: usize,
+}
+
+impl Polynomial {
+    /// fit polynomial to values, of degree at most `values.len() - 1`
+    pub fn fit(values: &[i64]) -> Self {
+        let mut row: Vec<i128> = values.iter().map(|&v| v as i128).collect();
+        let mut differences = vec![];
+        while row.iter().any(|&v| v != 0) {
+            differences.push(row[0]);
+            row = row.windows(2).map(|w| w[1] - w[0]).collect();
+        }
+        Self {
+            differences,
+            len: values.len(),
+        }
+    }
+
+    /// degree of polynomial, 0 for constant (and null) polynomials
+    pub fn degree(&self) -> usize {
+        self.differences.len().saturating_sub(1)
+    }
+
+    /// true if fitted values leave at least one extra value to confirm
+    /// the degree, otherwise any higher degree polynomial would fit as well
+    pub fn is_determined(&self) -> bool {
+        self.differences.len() < self.len
+    }
+
+    /// value at `x`, which may be negative or beyond fitted values, `None`
+    /// if a term of the Newton form does not fit in an `i128`
+    pub fn at(&self, x: i64) -> Option<i128> {
+        let x = x as i128;
+        let mut value: i128 = 0;
+        // binomial coefficient C(x, k)
+        let mut binomial: i128 = 1;
+        for (k, difference) in self.differences.iter().enumerate() {
+            if k > 0 {
+                // C(x, k) = C(x, k - 1) * (x - k + 1) / k, dividing first so
+                // that only a binomial too large overflows
+                let k = k as i128;
+                let g = gcd(binomial, k);
+                binomial = (binomial / g).checked_mul((x - k + 1) / (k / g))?;
+            }
+            value = value.checked_add(difference.checked_mul(binomial)?)?;
+        }
+        Some(value)
+    }
+
+    /// value `steps` after the last fitted one, see [`Polynomial::at`]
+    pub fn next(&self, steps: usize) -> Option<i128> {
+        self.at((self.len + steps) as i64 - 1)
+    }
+
+    /// value `steps` before the first fitted one, see [`Polynomial::at`]
+    pub fn previous(&self, steps: usize) -> Option<i128> {
+        self.at(-(steps as i64))
+    }
+}
+
+/// Lagrange interpolation: value at `x` of the polynomial of minimal degree
+/// through `points`, as a reduced fraction (numerator, positive denominator),
+/// `None` if an intermediate fraction does not fit in an `i128`
+///
+/// Panics if two points share the same x.
+pub fn interpolate(points: &[(i64, i64)], x: i64) -> Option<(i128, i128)> {
+    for (i, &(xi, _)) in points.iter().enumerate() {
+        assert!(
+            points[..i].iter().all(|&(xj, _)| xj != xi),
+            "points must have distinct x, {xi} is repeated"
+        );
+    }
+    let mut value = (0, 1);
+    for (i, &(xi, yi)) in points.iter().enumerate() {
+        // yi * product of (x - xj) / (xi - xj) over other points
+        let mut term = (yi as i128, 1);
+        for (j, &(xj, _)) in points.iter().enumerate() {
+            if i != j {
+                let factor = (x as i128 - xj as i128, xi as i128 - xj as i128);
+                term = multiply(term, factor)?;
+            }
+        }
+        value = add(value, term)?;
+    }
+    Some(value)
+}
+
+/// reduced fraction with positive denominator, `None` for `i128::MIN` which
+/// has no opposite
+fn reduce(n: i128, d: i128) -> Option<(i128, i128)> {
+    if n == i128::MIN || d == i128::MIN {
+        return None;
+    }
+    let g = gcd(n, d);
+    let (n, d) = (n / g, d / g);
+    Some(if d < 0 { (-n, -d) } else { (n, d) })
+}
+
+/// product of fractions, reducing across before multiplying
+fn multiply((a, b): (i128, i128), (c, d): (i128, i128)) -> Option<(i128, i128)> {
+    let (g1, g2) = (gcd(a, d), gcd(c, b));
+    reduce((a / g1).checked_mul(c / g2)?, (b / g2).checked_mul(d / g1)?)
+}
+
+/// sum of fractions with positive denominators, over their lcm
+fn add((a, b): (i128, i128), (c, d): (i128, i128)) -> Option<(i128, i128)> {
+    let g = gcd(b, d);
+    let n = a.checked_mul(d / g)?.checked_add(c.checked_mul(b / g)?)?;
+    reduce(n, (b / g).checked_mul(d)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit() {
+        let p = Polynomial::fit(&[0, 3, 6, 9, 12, 15]);
+        assert_eq!(p.degree(), 1);
+        assert!(p.is_determined());
+        assert_eq!((p.next(1), p.previous(1)), (Some(18), Some(-3)));
+
+        let p = Polynomial::fit(&[10, 13, 16, 21, 30, 45]);
+        assert_eq!(p.degree(), 3);
+        assert_eq!((p.next(1), p.previous(1)), (Some(68), Some(5)));
+        assert_eq!(p.at(2), Some(16));
+
+        // x^2 + 1 far away in both directions
+        let p = Polynomial::fit(&[1, 2, 5, 10]);
+        assert_eq!(p.at(1_000_000), Some(1_000_000_000_001));
+        assert_eq!(p.previous(10), Some(101));
+
+        let p = Polynomial::fit(&[0, 0]);
+        assert_eq!((p.degree(), p.next(5)), (0, Some(0)));
+        assert!(!Polynomial::fit(&[1, 2]).is_determined());
+    }
+
+    #[test]
+    fn overflow() {
+        // x^3 fits in an i128 up to about 5.5e12
+        let p = Polynomial::fit(&[0, 1, 8, 27, 64]);
+        assert_eq!(p.at(5_000_000_000_000), Some(125 * 10i128.pow(36)));
+        assert_eq!(p.at(-5_000_000_000_000), Some(-125 * 10i128.pow(36)));
+        assert_eq!(p.at(6_000_000_000_000), None);
+        assert_eq!(p.at(i64::MAX), None);
+        // C(x, k) stays exact for large coefficients
+        let p = Polynomial::fit(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(p.at(20), Some(184_756));
+    }
+
+    #[test]
+    #[should_panic(expected = "distinct x")]
+    fn lagrange_duplicate_x() {
+        interpolate(&[(1, 2), (3, 4), (1, 5)], 0);
+    }
+
+    #[test]
+    fn lagrange() {
+        let points = [(-1, 2), (1, 2), (3, 10)];
+        // x^2 + 1
+        assert_eq!(interpolate(&points, 0), Some((1, 1)));
+        assert_eq!(interpolate(&points, 5), Some((26, 1)));
+        // line through (0, 0) and (2, 1)
+        assert_eq!(interpolate(&[(0, 0), (2, 1)], 1), Some((1, 2)));
+        assert_eq!(interpolate(&[(0, 0), (2, 1)], -3), Some((-3, 2)));
+        assert_eq!(interpolate(&[], 3), Some((0, 1)));
+    }
+
+    #[test]
+    fn lagrange_overflow() {
+        let big = i64::MAX as i128;
+        let line = [(0, 0), (1, i64::MAX)];
+        assert_eq!(interpolate(&line, i64::MAX), Some((big * big, 1)));
+        assert_eq!(interpolate(&line, i64::MIN), Some((-big * (big + 1), 1)));
+        // -i64::MAX * x * (x - 2) is about 2^189
+        let parabola = [(0, 0), (1, i64::MAX), (2, 0)];
+        assert_eq!(interpolate(&parabola, i64::MAX), None);
+        assert_eq!(interpolate(&parabola, i64::MIN), None);
+        // far apart x do not overflow denominators
+        let points = [(i64::MIN, 0), (0, 1), (i64::MAX, 0)];
+        assert_eq!(interpolate(&points, 0), Some((1, 1)));
+    }
+}
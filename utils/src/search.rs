@@ -0,0 +1,281 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Result of a successful search
+#[derive(Debug, Clone)]
+pub struct Found<S, C> {
+    /// cost of optimal paths
+    pub cost: C,
+    /// goal states reached at optimal cost, in order of expansion
+    pub goals: Vec<S>,
+    /// number of states expanded
+    pub expanded: usize,
+    /// number of states pushed onto the open queue
+    pub pushed: usize,
+    /// best known cost of each reached state, along with its predecessors
+    /// on paths of that cost
+    best: HashMap<S, (C, Vec<S>)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Found<S, C> {
+    /// predecessors of state on optimal paths to it, empty for start states
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.best
+            .get(state)
+            .map_or(&[], |(_, predecessors)| predecessors.as_slice())
+    }
+
+    /// cost of optimal paths from start states to given reached state
+    pub fn cost_to(&self, state: &S) -> Option<C> {
+        self.best.get(state).map(|&(cost, _)| cost)
+    }
+
+    /// one optimal path, from a start state to the first goal
+    pub fn path(&self) -> Vec<S> {
+        let mut path = vec![self.goals[0].clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        path
+    }
+
+    /// every state on any optimal path to any goal
+    pub fn optimal_states(&self) -> HashSet<S> {
+        let mut states = HashSet::new();
+        let mut stack = self.goals.clone();
+        while let Some(state) = stack.pop() {
+            if states.insert(state.clone()) {
+                stack.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        states
+    }
+}
+
+/// Cheapest paths from any start state to a goal state, `successors` gives
+/// reachable states along with the (non-negative) cost to reach them.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], exploring states by increasing cost plus `heuristic`,
+/// an estimate of remaining cost to a goal which must never overestimate it
+/// (nor decrease by more than an edge cost along edges) for paths to be optimal.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut best: HashMap<S, (C, Vec<S>)> = HashMap::new();
+    let mut closed: HashSet<S> = HashSet::new();
+    let mut start_states: HashSet<S> = HashSet::new();
+    // queue of (estimated total cost, push order), pushed states are kept
+    // aside so states need no ordering
+    let mut open = BinaryHeap::new();
+    let mut pending: Vec<(S, C)> = vec![];
+    let mut goals = vec![];
+    let mut found: Option<C> = None;
+    let mut expanded = 0;
+
+    for start in starts {
+        start_states.insert(start.clone());
+        best.insert(start.clone(), (C::default(), vec![]));
+        open.push((Reverse(heuristic(&start)), Reverse(pending.len())));
+        pending.push((start, C::default()));
+    }
+
+    while let Some((Reverse(estimate), Reverse(idx))) = open.pop() {
+        // other optimal goals can only be found before costlier estimates
+        if found.is_some_and(|cost| estimate > cost) {
+            break;
+        }
+        let (state, cost) = pending[idx].clone();
+        if cost > best[&state].0 || !closed.insert(state.clone()) {
+            // outdated entry, state was reached with a lower cost since
+            continue;
+        }
+        if is_goal(&state) {
+            found = Some(cost);
+            goals.push(state);
+            continue;
+        }
+        expanded += 1;
+
+        for (next, step) in successors(&state) {
+            // start states have no predecessors
+            if start_states.contains(&next) {
+                continue;
+            }
+            let next_cost = cost + step;
+            if closed.contains(&next) {
+                // a closed state is only reached again at the same cost
+                // through zero-cost edges, which is another optimal path
+                // unless it leads back to an ancestor of state
+                if best[&next].0 == next_cost && !is_ancestor(&best, &next, &state) {
+                    best.get_mut(&next).unwrap().1.push(state.clone());
+                }
+                continue;
+            }
+            match best.get_mut(&next) {
+                Some((known, predecessors)) if next_cost == *known => {
+                    predecessors.push(state.clone());
+                    continue;
+                }
+                Some((known, _)) if next_cost > *known => continue,
+                _ => {}
+            }
+            best.insert(next.clone(), (next_cost, vec![state.clone()]));
+            open.push((
+                Reverse(next_cost + heuristic(&next)),
+                Reverse(pending.len()),
+            ));
+            pending.push((next, next_cost));
+        }
+    }
+
+    Some(Found {
+        cost: found?,
+        goals,
+        expanded,
+        pushed: pending.len(),
+        best,
+    })
+}
+
+/// true if `ancestor` is `state` or on a path of predecessors to it
+fn is_ancestor<S: Eq + Hash, C>(best: &HashMap<S, (C, Vec<S>)>, ancestor: &S, state: &S) -> bool {
+    let mut seen = HashSet::new();
+    let mut stack = vec![state];
+    while let Some(state) = stack.pop() {
+        if state == ancestor {
+            return true;
+        }
+        if seen.insert(state) {
+            stack.extend(best.get(state).into_iter().flat_map(|(_, p)| p));
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Cell = (usize, usize);
+
+    /// 4-neighbour moves on a grid of digits, entering a cell costs its digit
+    fn grid_successors<'a>(grid: &'a [&'a str]) -> impl FnMut(&Cell) -> Vec<(Cell, u32)> + 'a {
+        move |&(x, y)| {
+            let mut next = vec![];
+            for (dx, dy) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                let (nx, ny) = (x as isize + dx, y as isize + dy);
+                if nx < 0 || ny < 0 {
+                    continue;
+                }
+                let (nx, ny) = (nx as usize, ny as usize);
+                if let Some(c) = grid.get(ny).and_then(|row| row.as_bytes().get(nx)) {
+                    next.push(((nx, ny), (c - b'0') as u32));
+                }
+            }
+            next
+        }
+    }
+
+    const GRID: [&str; 3] = ["1191", "1111", "9911"];
+
+    #[test]
+    fn cheapest_path() {
+        let found = dijkstra([(0, 0)], grid_successors(&GRID), |&s| s == (3, 2)).unwrap();
+        assert_eq!(found.cost, 5);
+        let path = found.path();
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(3, 2)));
+        assert_eq!(path.len(), 6);
+        assert_eq!(found.cost_to(&(1, 1)), Some(2));
+        assert!(found.expanded > 0 && found.pushed >= found.expanded);
+
+        assert!(dijkstra([(0, 0)], grid_successors(&GRID), |&s| s == (9, 9)).is_none());
+    }
+
+    #[test]
+    fn optimal_paths() {
+        // two cheapest paths around the 9
+        let grid = ["111", "191", "111"];
+        let found = dijkstra([(0, 0)], grid_successors(&grid), |&s| s == (2, 2)).unwrap();
+        assert_eq!(found.cost, 4);
+        assert_eq!(found.predecessors(&(2, 2)).len(), 2);
+        let states = found.optimal_states();
+        assert_eq!(states.len(), 8);
+        assert!(!states.contains(&(1, 1)));
+    }
+
+    #[test]
+    fn zero_cost_cycle() {
+        // a <-> b for free, b -> c costs 1
+        let successors = |&s: &char| match s {
+            'a' => vec![('b', 0)],
+            'b' => vec![('a', 0), ('c', 1)],
+            _ => vec![],
+        };
+        let found = dijkstra(['a'], successors, |&s| s == 'c').unwrap();
+        assert_eq!(found.cost, 1);
+        assert_eq!(found.path(), vec!['a', 'b', 'c']);
+        assert!(found.predecessors(&'a').is_empty());
+        assert_eq!(found.predecessors(&'b'), &['a']);
+
+        // b is reached for free from c too once closed
+        let successors = |&s: &char| match s {
+            'a' => vec![('b', 1), ('c', 1)],
+            'b' => vec![('c', 0)],
+            'c' => vec![('b', 0), ('d', 1)],
+            _ => vec![],
+        };
+        let found = dijkstra(['a'], successors, |&s| s == 'd').unwrap();
+        assert_eq!(found.cost, 2);
+        assert_eq!(found.path().len(), 3);
+        assert_eq!(found.optimal_states().len(), 4);
+        // x closes before y, y -> x is still an optimal edge
+        let successors = |&s: &char| match s {
+            'a' => vec![('x', 1), ('y', 1)],
+            'y' => vec![('x', 0)],
+            'x' => vec![('g', 1)],
+            _ => vec![],
+        };
+        let found = dijkstra(['a'], successors, |&s| s == 'g').unwrap();
+        assert_eq!(found.cost, 2);
+        assert_eq!(found.predecessors(&'x'), &['a', 'y']);
+        assert_eq!(found.optimal_states().len(), 4);
+        assert!(found.optimal_states().contains(&'y'));
+    }
+
+    #[test]
+    fn heuristic() {
+        let target = (3, 2);
+        let manhattan = |&(x, y): &Cell| (target.0 - x + target.1 - y) as u32;
+        let found = astar([(0, 0)], grid_successors(&GRID), manhattan, |&s| {
+            s == target
+        })
+        .unwrap();
+        let plain = dijkstra([(0, 0)], grid_successors(&GRID), |&s| s == target).unwrap();
+        assert_eq!(found.cost, plain.cost);
+        assert!(found.expanded <= plain.expanded);
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use crate::alloc::{self, AllocStats};
+
+/// A day puzzle, input is parsed once then shared by both parts.
+///
+/// ```ignore
+/// pub struct Puzzle;
+///
+/// impl Solution for Puzzle {
+///     type Input = Vec<u64>;
+///
+///     fn parse(input: &str) -> Self::Input {
+///         input.lines().map(|line| line.parse().unwrap()).collect()
+///     }
+///
+///     fn part1(input: &Self::Input) -> impl Display {
+///         input.iter().sum::<u64>()
+///     }
+/// }
+/// ```
+///
+/// Parts which are not solved yet keep the default implementation and are
+/// marked in [`Solution::SOLVED`], see [`check_solved`].
+pub trait Solution {
+    /// parsed puzzle input
+    type Input;
+
+    /// whether each part is solved, known without any input
+    const SOLVED: [bool; 2] = [true, true];
+
+    fn parse(input: &str) -> Self::Input;
+
+    fn part1(_input: &Self::Input) -> impl Display {
+        Unsolved
+    }
+
+    fn part2(_input: &Self::Input) -> impl Display {
+        Unsolved
+    }
+}
+
+/// Answer of a part which is not solved yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not solved")
+    }
+}
+
+/// Whether given part (1 or 2) is solved, known without any input
+pub fn is_solved<S: Solution>(part: u32) -> bool {
+    match part {
+        1 | 2 => S::SOLVED[part as usize - 1],
+        _ => false,
+    }
+}
+
+/// Panic unless [`Solution::SOLVED`] marks as unsolved exactly the parts
+/// left to their default implementation, as answered for `input`
+pub fn check_solved<S: Solution>(input: &str) {
+    let input = S::parse(input);
+    let unsolved = Unsolved.to_string();
+    let answers = [S::part1(&input).to_string(), S::part2(&input).to_string()];
+    for (part, answer) in (1..).zip(answers) {
+        assert_eq!(
+            is_solved::<S>(part),
+            answer != unsolved,
+            "part {part} is{} marked solved",
+            if is_solved::<S>(part) { "" } else { " not" }
+        );
+    }
+}
+
+/// Answer given part (1 or 2) of already parsed input, `None` if part is not solved
+pub fn solve_part<S: Solution>(input: &S::Input, part: u32) -> Option<String> {
+    match part {
+        1 | 2 if !is_solved::<S>(part) => None,
+        1 => Some(S::part1(input).to_string()),
+        2 => Some(S::part2(input).to_string()),
+        _ => panic!("no part {part}"),
+    }
+}
+
+/// Answers of a run, along with time spent parsing and solving each part
+/// and allocations made solving it
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub parse: Duration,
+    /// allocations made parsing input
+    pub parse_alloc: AllocStats,
+    /// (answer, duration, allocations) of each requested part, in request order
+    pub parts: Vec<(Option<String>, Duration, AllocStats)>,
+}
+
+/// Parse input once then answer each requested part, timing each step
+pub fn solve_timed<S: Solution>(input: &str, parts: &[u32]) -> Timed {
+    let start = Instant::now();
+    let (input, parse_alloc) = alloc::measure(|| S::parse(input));
+    let parse = start.elapsed();
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (answer, alloc) = alloc::measure(|| solve_part::<S>(&input, part));
+            (answer, start.elapsed(), alloc)
+        })
+        .collect();
+    Timed {
+        parse,
+        parse_alloc,
+        parts,
+    }
+}
+
+/// Parse input once then answer each requested part, in request order
+pub fn solve<S: Solution>(input: &str, parts: &[u32]) -> Vec<Option<String>> {
+    solve_timed::<S>(input, parts)
+        .parts
+        .into_iter()
+        .map(|(answer, _, _)| answer)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u64>;
+
+        const SOLVED: [bool; 2] = [true, false];
+
+        fn parse(input: &str) -> Self::Input {
+            input.split(',').map(|s| s.parse().unwrap()).collect()
+        }
+
+        fn part1(input: &Self::Input) -> impl Display {
+            input.iter().sum::<u64>()
+        }
+    }
+
+    #[test]
+    fn parts() {
+        assert_eq!(
+            solve::<Sum>("1,2,3", &[1, 2]),
+            vec![Some("6".to_string()), None]
+        );
+        assert_eq!(solve::<Sum>("4", &[1]), vec![Some("4".to_string())]);
+    }
+
+    #[test]
+    fn timed() {
+        let timed = solve_timed::<Sum>("1,2,3", &[2, 1]);
+        assert_eq!(timed.parts.len(), 2);
+        assert_eq!(timed.parts[0].0, None);
+        assert_eq!(timed.parts[1].0, Some("6".to_string()));
+    }
+
+    #[test]
+    fn solved_parts() {
+        assert!(is_solved::<Sum>(1));
+        assert!(!is_solved::<Sum>(2));
+        assert!(!is_solved::<Sum>(3));
+        check_solved::<Sum>("1,2");
+    }
+
+    struct Unmarked;
+
+    impl Solution for Unmarked {
+        type Input = ();
+
+        fn parse(_input: &str) -> Self::Input {}
+
+        fn part1(_input: &Self::Input) -> impl Display {
+            1
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "part 2 is marked solved")]
+    fn unmarked_part() {
+        check_solved::<Unmarked>("");
+    }
+}
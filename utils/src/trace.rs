@@ -0,0 +1,260 @@
+//! Leveled debug output.
+//!
+//! Messages are written to stderr by the [`error!`](crate::error),
+//! [`warn!`](crate::warn), [`info!`](crate::info), [`debug!`](crate::debug)
+//! and [`trace!`](crate::trace) macros when their level is enabled for the
+//! module emitting them. The filter is read from `AOC_LOG`, e.g. `debug` or
+//! `warn,aoc2023_d17=trace`, unless the runner sets one with [`set_filter`].
+//!
+//! Debug and trace messages are compiled out of release builds unless the
+//! `trace` feature is enabled, so they can stay in solutions.
+
+use std::fmt;
+use std::io::Write;
+use std::sync::OnceLock;
+
+/// Environment variable holding the filter
+pub const LOG_VAR: &str = "AOC_LOG";
+
+/// Levels, from most to least important
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    /// parse level name, `off` is `Some(None)`
+    fn parse(name: &str) -> Option<Option<Level>> {
+        match name.to_ascii_lowercase().as_str() {
+            "off" => Some(None),
+            "error" => Some(Some(Level::Error)),
+            "warn" => Some(Some(Level::Warn)),
+            "info" => Some(Some(Level::Info)),
+            "debug" => Some(Some(Level::Debug)),
+            "trace" => Some(Some(Level::Trace)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+/// Most verbose level compiled in, messages above it are optimized out
+pub const MAX_LEVEL: Level = if cfg!(any(debug_assertions, feature = "trace")) {
+    Level::Trace
+} else {
+    Level::Info
+};
+
+/// Level of modules without directive when no filter is given
+const DEFAULT_LEVEL: Level = Level::Warn;
+
+/// Comma separated directives, `<level>` for every module or
+/// `<module>=<level>` for a module and its submodules
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    spec: String,
+    /// level of modules without directive, `None` is off
+    default: Option<Level>,
+    /// module path prefixes and their level
+    modules: Vec<(String, Option<Level>)>,
+}
+
+impl Filter {
+    pub fn parse(spec: &str) -> Result<Self, FilterError> {
+        let mut filter = Self {
+            spec: spec.to_string(),
+            default: Some(DEFAULT_LEVEL),
+            modules: vec![],
+        };
+        for directive in spec.split(',').map(str::trim) {
+            if directive.is_empty() {
+                continue;
+            }
+            let invalid = || FilterError::Invalid(directive.to_string());
+            match directive.split_once('=') {
+                Some((module, level)) => {
+                    let level = Level::parse(level.trim()).ok_or_else(invalid)?;
+                    filter.modules.push((module.trim().to_string(), level));
+                }
+                None => filter.default = Level::parse(directive).ok_or_else(invalid)?,
+            }
+        }
+        Ok(filter)
+    }
+
+    /// true if `level` messages of module at `path` are shown, the longest
+    /// matching module directive wins
+    pub fn enabled(&self, level: Level, path: &str) -> bool {
+        let matches = |module: &str| {
+            path.strip_prefix(module)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        };
+        let max = self
+            .modules
+            .iter()
+            .filter(|(module, _)| matches(module))
+            .max_by_key(|(module, _)| module.len())
+            .map_or(self.default, |&(_, level)| level);
+        max.is_some_and(|max| level <= max)
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self {
+            spec: String::new(),
+            default: Some(DEFAULT_LEVEL),
+            modules: vec![],
+        }
+    }
+}
+
+/// spec the filter was parsed from, suitable for `AOC_LOG`
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.spec)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterError {
+    /// directive which is not `<level>` or `<module>=<level>`
+    Invalid(String),
+}
+
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterError::Invalid(directive) => write!(
+                f,
+                "invalid log directive '{directive}', expected '<level>' or '<module>=<level>' with level one of off, error, warn, info, debug, trace"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FilterError {}
+
+static FILTER: OnceLock<Filter> = OnceLock::new();
+
+/// Set filter used by every module, fails once a message was checked
+/// against the filter read from `AOC_LOG`
+pub fn set_filter(filter: Filter) -> Result<(), Filter> {
+    FILTER.set(filter)
+}
+
+/// Filter in use, read from `AOC_LOG` on first use
+pub fn filter() -> &'static Filter {
+    FILTER.get_or_init(|| match std::env::var(LOG_VAR) {
+        Ok(spec) => Filter::parse(&spec).unwrap_or_else(|e| {
+            eprintln!("{LOG_VAR}: {e}");
+            Filter::default()
+        }),
+        Err(_) => Filter::default(),
+    })
+}
+
+/// true if `level` messages of module at `path` are shown
+#[inline]
+pub fn enabled(level: Level, path: &str) -> bool {
+    level <= MAX_LEVEL && filter().enabled(level, path)
+}
+
+#[doc(hidden)]
+pub fn emit(level: Level, path: &str, args: fmt::Arguments) {
+    let mut stderr = std::io::stderr().lock();
+    let _ = writeln!(stderr, "[{level:<5} {path}] {args}");
+}
+
+/// Emit message at given level, arguments are only evaluated when enabled
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {{
+        let level = $level;
+        if level <= $crate::trace::MAX_LEVEL && $crate::trace::enabled(level, module_path!()) {
+            $crate::trace::emit(level, module_path!(), format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels() {
+        let filter = Filter::default();
+        assert!(filter.enabled(Level::Warn, "aoc2023_d17"));
+        assert!(!filter.enabled(Level::Info, "aoc2023_d17"));
+
+        let filter = Filter::parse("debug").unwrap();
+        assert!(filter.enabled(Level::Debug, "utils::asciimap"));
+        assert!(!filter.enabled(Level::Trace, "utils::asciimap"));
+
+        let filter = Filter::parse("off").unwrap();
+        assert!(!filter.enabled(Level::Error, "aoc2023_d17"));
+    }
+
+    #[test]
+    fn modules() {
+        let filter = Filter::parse("info, aoc2023_d17=trace,aoc2023_d17::ultra=off").unwrap();
+        assert!(filter.enabled(Level::Trace, "aoc2023_d17"));
+        assert!(filter.enabled(Level::Trace, "aoc2023_d17::test"));
+        assert!(!filter.enabled(Level::Error, "aoc2023_d17::ultra"));
+        // prefix must end on a module boundary
+        assert!(!filter.enabled(Level::Debug, "aoc2023_d170"));
+        assert!(filter.enabled(Level::Info, "aoc2023_d16"));
+        assert_eq!(
+            filter.to_string(),
+            "info, aoc2023_d17=trace,aoc2023_d17::ultra=off"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for spec in ["verbose", "aoc2023_d17=loud"] {
+            assert!(matches!(Filter::parse(spec), Err(FilterError::Invalid(_))));
+        }
+    }
+}
@@ -0,0 +1,151 @@
+/// Disjoint sets of elements `0..n`, with path compression and union by size
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    /// parent of each element, roots are their own parent
+    parent: Vec<usize>,
+    /// size of each component, only meaningful for roots
+    size: Vec<usize>,
+    components: usize,
+}
+
+/// Merge of two components
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Merge {
+    /// root of merged component
+    pub root: usize,
+    /// root of the smaller component, merged into `root`
+    pub absorbed: usize,
+    /// size of merged component
+    pub size: usize,
+}
+
+impl UnionFind {
+    /// `n` components of a single element
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    /// number of elements
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// root of component of `x`
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // point every element of the path to root
+        let mut x = x;
+        while self.parent[x] != root {
+            x = std::mem::replace(&mut self.parent[x], root);
+        }
+        root
+    }
+
+    /// merge components of `a` and `b`, `None` if they are already the same
+    pub fn union(&mut self, a: usize, b: usize) -> Option<Merge> {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return None;
+        }
+        let (root, absorbed) = if self.size[a] < self.size[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parent[absorbed] = root;
+        self.size[root] += self.size[absorbed];
+        self.components -= 1;
+        Some(Merge {
+            root,
+            absorbed,
+            size: self.size[root],
+        })
+    }
+
+    /// merge components of `a` and `b`, calling `on_merge` if they were distinct,
+    /// return whether they were
+    pub fn union_with(&mut self, a: usize, b: usize, on_merge: impl FnOnce(Merge)) -> bool {
+        self.union(a, b).map(on_merge).is_some()
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// size of component of `x`
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// number of components
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// (root, size) of each component
+    pub fn component_sizes(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.parent
+            .iter()
+            .enumerate()
+            .filter(|&(x, &parent)| x == parent)
+            .map(|(root, _)| (root, self.size[root]))
+    }
+
+    /// sizes of the `k` largest components, largest first
+    pub fn largest(&self, k: usize) -> Vec<usize> {
+        let mut sizes: Vec<usize> = self.component_sizes().map(|(_, size)| size).collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.truncate(k);
+        sizes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union() {
+        let mut uf = UnionFind::new(6);
+        assert_eq!(uf.components(), 6);
+
+        let merge = uf.union(0, 1).unwrap();
+        assert_eq!(merge.size, 2);
+        assert!(uf.union(1, 0).is_none());
+        uf.union(2, 3);
+        // larger component absorbs smaller one
+        let merge = uf.union(4, 3).unwrap();
+        assert_eq!(merge.absorbed, 4);
+        assert_eq!(merge.size, 3);
+
+        assert_eq!(uf.components(), 3);
+        assert!(uf.same(2, 4));
+        assert!(!uf.same(0, 4));
+        assert_eq!(uf.size(4), 3);
+        assert_eq!(uf.largest(2), vec![3, 2]);
+        assert_eq!(uf.largest(10), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn merge_events() {
+        let mut uf = UnionFind::new(4);
+        let mut merges = vec![];
+        for (a, b) in [(0, 1), (1, 0), (2, 3), (0, 3)] {
+            uf.union_with(a, b, |merge| merges.push(merge.size));
+        }
+        assert_eq!(merges, vec![2, 2, 4]);
+        assert_eq!(uf.components(), 1);
+    }
+}